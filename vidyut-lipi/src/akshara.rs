@@ -0,0 +1,293 @@
+//! Utilities for splitting text into aksharas.
+//!
+//! An *akshara* is an orthographic syllable: a cluster of consonants, an optional vowel or vowel
+//! sign, and any ayogavahas and accent marks that follow. For example, *संस्कृतम्* contains the
+//! aksharas *सं*, *स्कृ*, *त*, and *म्*.
+//!
+//! We classify each token in a scheme by using the Devanagari key that it maps to, so this logic
+//! works for any scheme that `vidyut-lipi` supports.
+
+use crate::mapping::TokenKind;
+use crate::scheme::Scheme;
+use rustc_hash::FxHashMap;
+
+/// Models how a token participates in an akshara.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Kind {
+    /// A consonant.
+    Consonant,
+    /// An independent vowel.
+    Vowel,
+    /// A vowel sign.
+    VowelMark,
+    /// The virama.
+    Virama,
+    /// An ayogavaha (anusvara, visarga, candrabindu, etc.)
+    Ayogavaha,
+    /// An accent mark.
+    Accent,
+    /// Any other token, such as a space, numeral, or punctuation mark.
+    Other,
+}
+
+impl Kind {
+    fn from_devanagari_key(key: &str) -> Self {
+        const VIRAMA: &str = "\u{094d}";
+        const INDEPENDENT_VOWELS: &[std::ops::RangeInclusive<char>] = &[
+            '\u{0904}'..='\u{0914}',
+            '\u{0960}'..='\u{0961}',
+            '\u{0972}'..='\u{0977}',
+        ];
+
+        if key == VIRAMA {
+            return Kind::Virama;
+        }
+        match TokenKind::from_devanagari_key(key) {
+            TokenKind::Consonant => Kind::Consonant,
+            TokenKind::VowelMark => Kind::VowelMark,
+            TokenKind::Ayogavaha => Kind::Ayogavaha,
+            TokenKind::Accent => Kind::Accent,
+            TokenKind::Other => match key.chars().next() {
+                Some(c)
+                    if key.chars().count() == 1
+                        && INDEPENDENT_VOWELS.iter().any(|r| r.contains(&c)) =>
+                {
+                    Kind::Vowel
+                }
+                _ => Kind::Other,
+            },
+        }
+    }
+}
+
+/// An akshara within some larger text.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Akshara<'a> {
+    text: &'a str,
+    start: usize,
+}
+
+impl<'a> Akshara<'a> {
+    /// The text of this akshara.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The byte offset at which this akshara starts.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The byte offset at which this akshara ends (exclusive).
+    pub fn end(&self) -> usize {
+        self.start + self.text.len()
+    }
+}
+
+/// An iterator over the aksharas in some text.
+///
+/// This struct is created by the `aksharas` function.
+pub struct Aksharas<'a> {
+    text: &'a str,
+    /// Byte offset within `text`. This points to the start of the next akshara.
+    i: usize,
+    /// Maps this scheme's tokens to their kinds.
+    kinds: FxHashMap<&'static str, Kind>,
+    /// The length of the longest key in `kinds`, in chars.
+    len_longest_key: usize,
+    is_alphabet: bool,
+}
+
+impl<'a> Aksharas<'a> {
+    fn new(text: &'a str, scheme: Scheme) -> Self {
+        let mut kinds = FxHashMap::default();
+
+        // Also classify Unicode equivalents.
+        let mut unicode_alts: FxHashMap<&str, Vec<&'static str>> = FxHashMap::default();
+        for (nfc, nfd) in scheme.unicode_nfd_pairs() {
+            unicode_alts.entry(nfc).or_default().push(nfd);
+            unicode_alts.entry(nfd).or_default().push(nfc);
+        }
+
+        for (deva_key, value) in scheme.token_pairs() {
+            if value.is_empty() {
+                // For example, the virama in alphabetic schemes.
+                continue;
+            }
+            let mut kind = Kind::from_devanagari_key(deva_key);
+            if scheme.is_alphabet() && kind == Kind::VowelMark {
+                // Alphabets use the same tokens for vowels and vowel signs.
+                kind = Kind::Vowel;
+            }
+
+            // Insert only the first match seen so that the vowel sign takes priority.
+            kinds.entry(*value).or_insert(kind);
+            if let Some(alts) = unicode_alts.get(value) {
+                for alt in alts {
+                    kinds.entry(*alt).or_insert(kind);
+                }
+            }
+        }
+
+        let len_longest_key = kinds.keys().map(|k| k.chars().count()).max().unwrap_or(0);
+        Self {
+            text,
+            i: 0,
+            kinds,
+            len_longest_key,
+            is_alphabet: scheme.is_alphabet(),
+        }
+    }
+
+    /// Returns the kind and byte length of the longest token that starts at byte offset `i`.
+    fn next_token(&self, i: usize) -> Option<(Kind, usize)> {
+        let slice = &self.text[i..];
+        let first = slice.chars().next()?;
+
+        for len_key in (1..=self.len_longest_key).rev() {
+            let j = match slice.char_indices().nth(len_key) {
+                Some((j, _)) => j,
+                None => slice.len(),
+            };
+            if let Some(kind) = self.kinds.get(&slice[..j]) {
+                return Some((*kind, j));
+            }
+        }
+        Some((Kind::Other, first.len_utf8()))
+    }
+
+    /// Returns whether a token of kind `next` continues an akshara whose last token has kind
+    /// `prev`.
+    fn continues(&self, prev: Kind, next: Kind, has_vowel: bool) -> bool {
+        use Kind::*;
+        match next {
+            // Abugidas join consonants with the virama. Alphabets join them implicitly.
+            Consonant => !has_vowel && (prev == Virama || (self.is_alphabet && prev == Consonant)),
+            Vowel => self.is_alphabet && !has_vowel && prev == Consonant,
+            VowelMark => !has_vowel && prev == Consonant,
+            Virama => prev == Consonant,
+            Ayogavaha | Accent => prev != Other,
+            Other => false,
+        }
+    }
+}
+
+impl<'a> Iterator for Aksharas<'a> {
+    type Item = Akshara<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.i;
+        let (mut prev, len) = self.next_token(start)?;
+        let mut has_vowel = matches!(prev, Kind::Vowel | Kind::VowelMark);
+        let mut end = start + len;
+
+        if prev != Kind::Other {
+            while let Some((kind, len)) = self.next_token(end) {
+                if !self.continues(prev, kind, has_vowel) {
+                    break;
+                }
+                has_vowel |= matches!(kind, Kind::Vowel | Kind::VowelMark);
+                prev = kind;
+                end += len;
+            }
+        }
+
+        self.i = end;
+        Some(Akshara {
+            text: &self.text[start..end],
+            start,
+        })
+    }
+}
+
+/// Splits the given text into aksharas.
+///
+/// Each akshara contains a cluster of consonants, an optional vowel, and any ayogavahas and accent
+/// marks that follow. All other characters (spaces, numerals, punctuation, etc.) are returned as
+/// single-character aksharas.
+///
+/// `aksharas` is meant mainly for Brahmic schemes. For alphabetic schemes, it groups sounds in the
+/// same way, so that `saṃskṛtam` splits into `saṃ`, `skṛ`, `ta`, and `m`.
+///
+/// ### Usage
+///
+/// ```
+/// use vidyut_lipi::{aksharas, Scheme};
+///
+/// let text = "संस्कृतम्";
+/// let spans: Vec<_> = aksharas(text, Scheme::Devanagari).map(|a| a.text()).collect();
+/// assert_eq!(spans, vec!["सं", "स्कृ", "त", "म्"]);
+///
+/// let first = aksharas(text, Scheme::Devanagari).next().unwrap();
+/// assert_eq!((first.start(), first.end()), (0, 6));
+/// ```
+pub fn aksharas(text: &str, scheme: Scheme) -> Aksharas<'_> {
+    Aksharas::new(text, scheme)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Scheme::*;
+
+    fn split(text: &str, scheme: Scheme) -> Vec<&str> {
+        aksharas(text, scheme).map(|a| a.text()).collect()
+    }
+
+    #[test]
+    fn devanagari() {
+        assert_eq!(split("संस्कृतम्", Devanagari), &["सं", "स्कृ", "त", "म्"]);
+        assert_eq!(split("अग्निमीळे", Devanagari), &["अ", "ग्नि", "मी", "ळे"]);
+        assert_eq!(split("रामः", Devanagari), &["रा", "मः"]);
+        assert_eq!(split("ऐश्वर्यँ", Devanagari), &["ऐ", "श्व", "र्यँ"]);
+    }
+
+    #[test]
+    fn devanagari_with_other_text() {
+        assert_eq!(
+            split("राम। १२ x", Devanagari),
+            &["रा", "म", "।", " ", "१", "२", " ", "x"]
+        );
+    }
+
+    #[test]
+    fn devanagari_with_accents() {
+        assert_eq!(split("अ॒ग्निमी॑ळे", Devanagari), &["अ॒", "ग्नि", "मी॑", "ळे"]);
+    }
+
+    #[test]
+    fn devanagari_with_nfd_consonants() {
+        // Both NFC and NFD "qa"
+        assert_eq!(split("\u{0958}ि", Devanagari), &["\u{0958}ि"]);
+        assert_eq!(
+            split("\u{0915}\u{093c}ि", Devanagari),
+            &["\u{0915}\u{093c}ि"]
+        );
+    }
+
+    #[test]
+    fn other_brahmic_schemes() {
+        assert_eq!(split("ಸಂಸ್ಕೃತಮ್", Kannada), &["ಸಂ", "ಸ್ಕೃ", "ತ", "ಮ್"]);
+        assert_eq!(split("সংস্কৃতম্", Bengali), &["সং", "স্কৃ", "ত", "ম্"]);
+        assert_eq!(split("𑌸𑌂𑌸𑍍𑌕𑍃𑌤𑌮𑍍", Grantha), &["𑌸𑌂", "𑌸𑍍𑌕𑍃", "𑌤", "𑌮𑍍"]);
+    }
+
+    #[test]
+    fn alphabets() {
+        assert_eq!(split("saṃskṛtam", Iast), &["saṃ", "skṛ", "ta", "m"]);
+        assert_eq!(split("saMskftam", Slp1), &["saM", "skf", "ta", "m"]);
+        assert_eq!(split("aiSvarya", HarvardKyoto), &["ai", "Sva", "rya"]);
+    }
+
+    #[test]
+    fn spans_cover_input() {
+        let text = "नमस्ते विश्व";
+        let mut i = 0;
+        for a in aksharas(text, Devanagari) {
+            assert_eq!(a.start(), i);
+            assert_eq!(&text[a.start()..a.end()], a.text());
+            i = a.end();
+        }
+        assert_eq!(i, text.len());
+    }
+}
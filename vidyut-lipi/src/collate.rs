@@ -0,0 +1,346 @@
+//! Utilities for sorting text in traditional Sanskrit order.
+//!
+//! Byte-wise sorting is wrong in every scheme. For example, SLP1 places `A` before `a`, and
+//! Devanagari places independent vowels and vowel signs in different Unicode blocks. So, we
+//! transliterate our input to SLP1 and map each sound to its position in the *varṇamālā*.
+
+use crate::mapping::Mapping;
+use crate::scheme::Scheme;
+use crate::transliterate::transliterate;
+
+// Weights for whitespace and digits. These sort before all Sanskrit sounds.
+const WEIGHT_SPACE: u32 = 1;
+const WEIGHT_DIGIT_0: u32 = 10;
+
+// Weights for sounds in the varṇamālā. We leave gaps between groups so that we can later add new
+// sounds without changing existing keys too much.
+const WEIGHT_VOWEL_START: u32 = 100;
+const WEIGHT_CANDRABINDU: u32 = 200;
+const WEIGHT_ANUSVARA: u32 = 201;
+const WEIGHT_VISARGA: u32 = 202;
+const WEIGHT_CONSONANT_START: u32 = 300;
+const WEIGHT_KSHA: u32 = 400;
+const WEIGHT_JNA: u32 = 401;
+
+// Weights for all other characters that aren't part of the varṇamālā (punctuation, Latin letters,
+// and so on). These sort after all Sanskrit sounds in code point order.
+const WEIGHT_OTHER_START: u32 = 1000;
+
+/// Vowels in SLP1, in traditional order.
+///
+/// `è` and `ò` are the short *e* and *o* that appear in some Dravidian languages. We place them
+/// just before their long counterparts.
+const VOWELS: &[char] = &[
+    'a', 'A', 'i', 'I', 'u', 'U', 'f', 'F', 'x', 'X', 'è', 'e', 'E', 'ò', 'o', 'O',
+];
+
+/// Consonants in SLP1, in traditional order.
+const CONSONANTS: &[char] = &[
+    'k', 'K', 'g', 'G', 'N', 'c', 'C', 'j', 'J', 'Y', 'w', 'W', 'q', 'Q', 'R', 't', 'T', 'd', 'D',
+    'n', 'p', 'P', 'b', 'B', 'm', 'y', 'r', 'l', 'v', 'S', 'z', 's', 'h', 'L', '|',
+];
+
+/// A key for sorting text in traditional Sanskrit order.
+///
+/// Keys are comparable only if they were created with the same `Collator` settings.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SortKey(Vec<u32>);
+
+/// Creates sort keys for text in some `Scheme`.
+///
+/// `Collator` stores the `Mapping` it needs to read its input scheme, so we recommend reusing a
+/// single `Collator` when sorting many strings.
+///
+/// ### Usage
+///
+/// ```
+/// use vidyut_lipi::{Collator, Scheme};
+///
+/// let collator = Collator::new(Scheme::Iast);
+///
+/// let mut words = vec!["kṣetra", "jñāna", "ākāśa", "agni", "hari"];
+/// words.sort_by_cached_key(|w| collator.sort_key(w));
+/// assert_eq!(words, vec!["agni", "ākāśa", "kṣetra", "jñāna", "hari"]);
+///
+/// // Sort `kṣ` and `jñ` as separate letters at the end of the alphabet.
+/// let collator = Collator::new(Scheme::Iast).with_ksha_jna_as_letters(true);
+/// words.sort_by_cached_key(|w| collator.sort_key(w));
+/// assert_eq!(words, vec!["agni", "ākāśa", "hari", "kṣetra", "jñāna"]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Collator {
+    mapping: Option<Mapping>,
+    homorganic_anusvara: bool,
+    visarga_as_s: bool,
+    ksha_jna_as_letters: bool,
+}
+
+impl Collator {
+    /// Creates a new collator for text in the given `scheme`.
+    ///
+    /// By default, anusvara and visarga sort after all vowels and before all consonants, and
+    /// *kṣa* and *jña* sort as ordinary consonant clusters.
+    pub fn new(scheme: Scheme) -> Self {
+        let mapping = if scheme == Scheme::Slp1 {
+            None
+        } else {
+            Some(Mapping::new(scheme, Scheme::Slp1))
+        };
+        Self {
+            mapping,
+            homorganic_anusvara: false,
+            visarga_as_s: false,
+            ksha_jna_as_letters: false,
+        }
+    }
+
+    /// If `true`, sort an anusvara before a stop consonant as the nasal of that stop's class.
+    ///
+    /// For example, *saṃkalpa* sorts as if it were *saṅkalpa*. This is the convention followed by
+    /// many Western dictionaries.
+    pub fn with_homorganic_anusvara(mut self, value: bool) -> Self {
+        self.homorganic_anusvara = value;
+        self
+    }
+
+    /// If `true`, sort visarga (along with jihvāmūlīya and upadhmānīya) as if it were *s*.
+    ///
+    /// For example, *duḥkha* sorts as if it were *duskha*.
+    pub fn with_visarga_as_s(mut self, value: bool) -> Self {
+        self.visarga_as_s = value;
+        self
+    }
+
+    /// If `true`, sort *kṣa* and *jña* as separate letters after *ha*.
+    ///
+    /// This is the convention followed by many Hindi and Marathi dictionaries.
+    pub fn with_ksha_jna_as_letters(mut self, value: bool) -> Self {
+        self.ksha_jna_as_letters = value;
+        self
+    }
+
+    /// Creates a sort key for the given text.
+    ///
+    /// Accent marks and avagrahas are ignored. Whitespace sorts first, then digits in numeric
+    /// order, then all Sanskrit sounds. All other characters sort after all Sanskrit sounds in code
+    /// point order.
+    pub fn sort_key(&self, text: impl AsRef<str>) -> SortKey {
+        match &self.mapping {
+            Some(m) => self.slp1_sort_key(&transliterate(text.as_ref(), m)),
+            None => self.slp1_sort_key(text.as_ref()),
+        }
+    }
+
+    fn slp1_sort_key(&self, slp1: &str) -> SortKey {
+        let chars: Vec<char> = slp1.chars().collect();
+        let mut key = Vec::with_capacity(chars.len());
+
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+
+            if self.ksha_jna_as_letters {
+                match (c, next) {
+                    ('k', Some('z')) => {
+                        key.push(WEIGHT_KSHA);
+                        i += 2;
+                        continue;
+                    }
+                    ('j', Some('Y')) => {
+                        key.push(WEIGHT_JNA);
+                        i += 2;
+                        continue;
+                    }
+                    _ => (),
+                }
+            }
+
+            let weight = match c {
+                'M' if self.homorganic_anusvara => match next.and_then(homorganic_nasal) {
+                    Some(nasal) => consonant_weight(nasal),
+                    None => Some(WEIGHT_ANUSVARA),
+                },
+                'H' | 'Z' | 'V' if self.visarga_as_s => consonant_weight('s'),
+                _ => weight(c),
+            };
+            if let Some(w) = weight {
+                key.push(w);
+            }
+            i += 1;
+        }
+
+        SortKey(key)
+    }
+}
+
+/// Creates a sort key for `text` in traditional Sanskrit order.
+///
+/// `sort_key` creates a new `Collator` on each call. If you are sorting many strings, we
+/// recommend creating a single `Collator` and reusing it.
+///
+/// ### Usage
+///
+/// ```
+/// use vidyut_lipi::{sort_key, Scheme};
+///
+/// let mut words = vec!["हरि", "अग्नि", "कमल", "आत्मन्"];
+/// words.sort_by_key(|w| sort_key(w, Scheme::Devanagari));
+/// assert_eq!(words, vec!["अग्नि", "आत्मन्", "कमल", "हरि"]);
+/// ```
+pub fn sort_key(text: impl AsRef<str>, scheme: Scheme) -> SortKey {
+    Collator::new(scheme).sort_key(text)
+}
+
+/// Returns the weight of the given SLP1 character, or `None` if it should be ignored.
+fn weight(c: char) -> Option<u32> {
+    let w = match c {
+        // Accent marks and avagraha.
+        '^' | '\\' | '\'' => return None,
+        'H' | 'Z' | 'V' => WEIGHT_VISARGA,
+        'M' => WEIGHT_ANUSVARA,
+        '~' => WEIGHT_CANDRABINDU,
+        '0'..='9' => WEIGHT_DIGIT_0 + (c as u32 - '0' as u32),
+        _ if c.is_whitespace() => WEIGHT_SPACE,
+        _ => {
+            if let Some(i) = VOWELS.iter().position(|x| *x == c) {
+                WEIGHT_VOWEL_START + i as u32
+            } else if let Some(w) = consonant_weight(c) {
+                w
+            } else {
+                WEIGHT_OTHER_START + c as u32
+            }
+        }
+    };
+    Some(w)
+}
+
+fn consonant_weight(c: char) -> Option<u32> {
+    CONSONANTS
+        .iter()
+        .position(|x| *x == c)
+        .map(|i| WEIGHT_CONSONANT_START + i as u32)
+}
+
+/// Returns the nasal in the same class as the stop consonant `c`, if one exists.
+fn homorganic_nasal(c: char) -> Option<char> {
+    let nasal = match c {
+        'k' | 'K' | 'g' | 'G' | 'N' => 'N',
+        'c' | 'C' | 'j' | 'J' | 'Y' => 'Y',
+        'w' | 'W' | 'q' | 'Q' | 'R' => 'R',
+        't' | 'T' | 'd' | 'D' | 'n' => 'n',
+        'p' | 'P' | 'b' | 'B' | 'm' => 'm',
+        _ => return None,
+    };
+    Some(nasal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheme::Coverage;
+    use crate::Lipika;
+
+    fn sorted(collator: &Collator, words: &[&'static str]) -> Vec<&'static str> {
+        let mut ret = words.to_vec();
+        ret.sort_by_key(|w| collator.sort_key(w));
+        ret
+    }
+
+    #[test]
+    fn vowels_and_consonants() {
+        let c = Collator::new(Scheme::Slp1);
+        let varnamala = "a A i I u U f F x X e E o O M H k K g G N c C j J Y w W q Q R t T d D n p P b B m y r l v S z s h";
+        let expected: Vec<_> = varnamala.split(' ').collect();
+
+        let mut actual = expected.clone();
+        actual.reverse();
+        actual.sort_by_key(|w| c.sort_key(w));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn prefixes_sort_first() {
+        let c = Collator::new(Scheme::Slp1);
+        assert!(c.sort_key("rAma") < c.sort_key("rAmaH"));
+        assert!(c.sort_key("rAma") < c.sort_key("rAmAyaRa"));
+        assert!(c.sort_key("deva") < c.sort_key("deva dattaH"));
+    }
+
+    #[test]
+    fn ignores_accents_and_avagraha() {
+        let c = Collator::new(Scheme::Slp1);
+        assert_eq!(c.sort_key("agni\\m"), c.sort_key("agnim"));
+        assert_eq!(c.sort_key("so 'ham"), c.sort_key("so ham"));
+    }
+
+    #[test]
+    fn anusvara() {
+        let words = &["saMskfta", "saMkalpa", "saNga", "saMvAda", "sakala"];
+
+        let c = Collator::new(Scheme::Slp1);
+        assert_eq!(
+            sorted(&c, words),
+            &["saMkalpa", "saMvAda", "saMskfta", "sakala", "saNga"]
+        );
+
+        let c = Collator::new(Scheme::Slp1).with_homorganic_anusvara(true);
+        assert_eq!(c.sort_key("saMkalpa"), c.sort_key("saNkalpa"));
+        assert_eq!(
+            sorted(&c, words),
+            &["saMvAda", "saMskfta", "sakala", "saMkalpa", "saNga"]
+        );
+    }
+
+    #[test]
+    fn visarga() {
+        let words = &["duHKa", "durga", "dugDa", "duzwa"];
+
+        let c = Collator::new(Scheme::Slp1);
+        assert_eq!(sorted(&c, words), &["duHKa", "dugDa", "durga", "duzwa"]);
+
+        let c = Collator::new(Scheme::Slp1).with_visarga_as_s(true);
+        assert_eq!(c.sort_key("duHKa"), c.sort_key("dusKa"));
+        assert_eq!(sorted(&c, words), &["dugDa", "durga", "duzwa", "duHKa"]);
+    }
+
+    #[test]
+    fn ksha_and_jna() {
+        let words = &["kzetra", "kOmudI", "jYAna", "jIva", "hari"];
+
+        let c = Collator::new(Scheme::Slp1);
+        assert_eq!(
+            sorted(&c, words),
+            &["kOmudI", "kzetra", "jIva", "jYAna", "hari"]
+        );
+
+        let c = Collator::new(Scheme::Slp1).with_ksha_jna_as_letters(true);
+        assert_eq!(
+            sorted(&c, words),
+            &["kOmudI", "jIva", "hari", "kzetra", "jYAna"]
+        );
+    }
+
+    #[test]
+    fn keys_agree_across_schemes() {
+        let slp1_words = &[
+            "saMskftam",
+            "kzetrajYaH",
+            "agnimIle purohitam",
+            "vAgarTAviva",
+        ];
+        let slp1 = Collator::new(Scheme::Slp1);
+
+        let mut lipika = Lipika::new();
+        for scheme in Scheme::iter() {
+            if !matches!(scheme.coverage(), Coverage::Complete | Coverage::Classical) {
+                continue;
+            }
+            let c = Collator::new(*scheme);
+            for word in slp1_words {
+                let text = lipika.transliterate(word, Scheme::Slp1, *scheme);
+                assert_eq!(c.sort_key(&text), slp1.sort_key(word), "{scheme:?}: {text}");
+            }
+        }
+    }
+}
@@ -0,0 +1,359 @@
+//! Utilities for exporting a `Mapping` to other formats.
+//!
+//! External tools (keyboards, ICU transliterators, font test sheets, etc.) often need to know how
+//! `vidyut-lipi` maps one scheme to another. Rather than maintaining a separate copy of our data,
+//! these tools can export our resolved `Mapping` directly.
+
+use crate::mapping::{Mapping, Token, TokenKind};
+use rustc_hash::FxHashSet;
+use std::fmt::Write;
+
+impl TokenKind {
+    /// A short, stable name for this token kind.
+    fn name(&self) -> &'static str {
+        match self {
+            TokenKind::Consonant => "consonant",
+            TokenKind::VowelMark => "vowel_mark",
+            TokenKind::Ayogavaha => "ayogavaha",
+            TokenKind::Accent => "accent",
+            TokenKind::Other => "other",
+        }
+    }
+}
+
+impl Mapping {
+    /// Returns the tokens in this mapping, sorted by input string.
+    ///
+    /// We skip empty inputs (such as the virama in alphabetic schemes), which `transliterate`
+    /// never matches.
+    fn sorted_tokens(&self) -> Vec<(&String, &Token)> {
+        let mut ret: Vec<_> = self.all.iter().filter(|(k, _)| !k.is_empty()).collect();
+        ret.sort_by(|x, y| x.0.cmp(y.0));
+        ret
+    }
+
+    /// Returns the vowel marks in this mapping, sorted by input string.
+    fn sorted_marks(&self) -> Vec<(&String, &String)> {
+        let mut ret: Vec<_> = self.marks.iter().filter(|(k, _)| !k.is_empty()).collect();
+        ret.sort_by(|x, y| x.0.cmp(y.0));
+        ret
+    }
+
+    /// Exports this mapping as a JSON string.
+    ///
+    /// The output contains every token in the mapping, including the tokens that `Mapping::new`
+    /// creates programmatically, along with the vowel marks and viramas that control how tokens
+    /// combine with each other. Entries are sorted by input so that the output is stable.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use vidyut_lipi::{Mapping, Scheme};
+    ///
+    /// let m = Mapping::new(Scheme::HarvardKyoto, Scheme::Devanagari);
+    /// let json = m.to_json();
+    /// assert!(json.contains(r#"{"input": "k", "output": "क", "kind": "consonant"}"#));
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        out += "{\n";
+        let _ = writeln!(
+            out,
+            "  \"from\": {},",
+            json_string(&format!("{:?}", self.from))
+        );
+        let _ = writeln!(out, "  \"to\": {},", json_string(&format!("{:?}", self.to)));
+        let _ = writeln!(
+            out,
+            "  \"from_virama\": {},",
+            json_string(&self.from_map.virama)
+        );
+        let _ = writeln!(
+            out,
+            "  \"to_virama\": {},",
+            json_string(&self.to_map.virama)
+        );
+
+        out += "  \"tokens\": [\n";
+        let tokens = self.sorted_tokens();
+        for (i, (input, token)) in tokens.iter().enumerate() {
+            let comma = if i + 1 < tokens.len() { "," } else { "" };
+            let _ = writeln!(
+                out,
+                "    {{\"input\": {}, \"output\": {}, \"kind\": \"{}\"}}{comma}",
+                json_string(input),
+                json_string(&token.text),
+                token.kind.name(),
+            );
+        }
+        out += "  ],\n";
+
+        out += "  \"marks\": [\n";
+        let marks = self.sorted_marks();
+        for (i, (input, output)) in marks.iter().enumerate() {
+            let comma = if i + 1 < marks.len() { "," } else { "" };
+            let _ = writeln!(
+                out,
+                "    {{\"input\": {}, \"output\": {}}}{comma}",
+                json_string(input),
+                json_string(output),
+            );
+        }
+        out += "  ]\n";
+        out += "}\n";
+        out
+    }
+
+    /// Exports this mapping as a set of ICU transform rules.
+    ///
+    /// The output can be passed to ICU's `Transliterator::createFromRules` and similar APIs. Since
+    /// ICU rules are tried in order, we list longer inputs first so that ICU, like
+    /// `transliterate`, prefers the longest match.
+    ///
+    /// These rules model how consonants, vowel marks, and viramas combine. They do not model the
+    /// script-specific reshaping that `transliterate` applies for schemes like Tamil, Tibetan, and
+    /// Khmer, nor the non-decimal numerals used by Grantha.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use vidyut_lipi::{Mapping, Scheme};
+    ///
+    /// let m = Mapping::new(Scheme::HarvardKyoto, Scheme::Devanagari);
+    /// let rules = m.to_icu_rules();
+    /// assert!(rules.contains("kA > का ;"));
+    /// assert!(rules.contains("k > क् ;"));
+    /// ```
+    pub fn to_icu_rules(&self) -> String {
+        let mut rules: Vec<(String, String)> = Vec::new();
+
+        let is_from_abugida = self.from.is_abugida();
+        let is_to_abugida = self.to.is_abugida();
+        let from_a = &self.from_map.letter_a;
+        let to_a = &self.to_map.letter_a;
+        let from_virama = &self.from_map.virama;
+        let to_virama = &self.to_map.virama;
+
+        let tokens = self.sorted_tokens();
+        let marks = self.sorted_marks();
+        for (input, token) in &tokens {
+            if !token.is_consonant() || is_from_abugida == is_to_abugida {
+                rules.push((input.to_string(), token.text.clone()));
+                continue;
+            }
+
+            if is_to_abugida {
+                // Alphabet to abugida: a consonant takes a vowel mark, or a virama if no vowel
+                // follows.
+                for (mark_in, mark_out) in &marks {
+                    rules.push((
+                        format!("{input}{mark_in}"),
+                        format!("{}{mark_out}", token.text),
+                    ));
+                }
+                rules.push((format!("{input}{from_a}"), token.text.clone()));
+                rules.push((input.to_string(), format!("{}{to_virama}", token.text)));
+            } else {
+                // Abugida to alphabet: a consonant has an implicit "a" unless it is followed by a
+                // vowel mark or virama.
+                for (mark_in, mark_out) in &marks {
+                    rules.push((
+                        format!("{input}{mark_in}"),
+                        format!("{}{mark_out}", token.text),
+                    ));
+                }
+                if !from_virama.is_empty() {
+                    rules.push((format!("{input}{from_virama}"), token.text.clone()));
+                }
+                rules.push((input.to_string(), format!("{}{to_a}", token.text)));
+            }
+        }
+
+        // Prefer the longest match. `sort_by_key` is stable, so rules with the same length keep their
+        // original order.
+        rules.sort_by_key(|(input, _)| std::cmp::Reverse(input.chars().count()));
+        let mut seen = FxHashSet::default();
+        rules.retain(|(input, _)| seen.insert(input.clone()));
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "# Generated by vidyut-lipi: {:?} > {:?}",
+            self.from, self.to
+        );
+        for (input, output) in rules {
+            let _ = writeln!(out, "{} > {} ;", icu_escape(&input), icu_escape(&output));
+        }
+        out
+    }
+}
+
+/// Escapes `s` as a JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out += "\\\"",
+            '\\' => out += "\\\\",
+            '\n' => out += "\\n",
+            '\r' => out += "\\r",
+            '\t' => out += "\\t",
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Escapes `s` for use in an ICU transform rule.
+///
+/// ICU requires that all ASCII characters other than letters and digits be escaped.
+fn icu_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_ascii() && !c.is_ascii_alphanumeric() {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scheme::Scheme::*;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a"), r#""a""#);
+        assert_eq!(json_string("\""), r#""\"""#);
+        assert_eq!(json_string("\\"), r#""\\""#);
+        assert_eq!(json_string("\u{1}"), r#""\u0001""#);
+        assert_eq!(json_string("क"), r#""क""#);
+    }
+
+    #[test]
+    fn test_icu_escape() {
+        assert_eq!(icu_escape("kh"), "kh");
+        assert_eq!(icu_escape(".m"), "\\.m");
+        assert_eq!(icu_escape("'"), "\\'");
+        assert_eq!(icu_escape(" "), "\\ ");
+        assert_eq!(icu_escape("क"), "क");
+    }
+
+    #[test]
+    fn to_json_includes_synthesized_tokens() {
+        // SLP1 has no separate symbol for "kSa", so `Mapping::new` creates one.
+        let m = Mapping::new(Devanagari, Slp1);
+        let json = m.to_json();
+        assert!(json.contains(r#""from": "Devanagari""#));
+        assert!(json.contains(r#""to_virama": """#));
+        assert!(json.contains(r#"{"input": "क", "output": "k", "kind": "consonant"}"#));
+        assert!(json.contains(r#"{"input": "ा", "output": "A"}"#));
+
+        for (input, token) in m.all.iter().filter(|(k, _)| !k.is_empty()) {
+            assert!(json.contains(&format!(
+                "{{\"input\": {}, \"output\": {}",
+                json_string(input),
+                json_string(&token.text)
+            )));
+        }
+    }
+
+    /// Applies ICU rules in the same way as ICU: at each position, use the first rule that
+    /// matches.
+    fn apply_icu_rules(rules: &str, input: &str) -> String {
+        let unescape = |s: &str| s.replace('\\', "");
+        let rules: Vec<(String, String)> = rules
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .map(|line| {
+                let (lhs, rhs) = line.split_once(" > ").expect("valid rule");
+                (unescape(lhs), unescape(rhs.trim_end_matches(" ;")))
+            })
+            .collect();
+
+        let mut out = String::new();
+        let mut i = 0;
+        while i < input.len() {
+            let rest = &input[i..];
+            match rules.iter().find(|(lhs, _)| rest.starts_with(lhs.as_str())) {
+                Some((lhs, rhs)) => {
+                    out += rhs;
+                    i += lhs.len();
+                }
+                None => {
+                    let c = rest.chars().next().expect("non-empty");
+                    out.push(c);
+                    i += c.len_utf8();
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn to_icu_rules_agree_with_transliterate() {
+        use crate::transliterate::transliterate;
+
+        let cases = &[
+            (
+                HarvardKyoto,
+                Devanagari,
+                "saMskRtam namaskRtya kSatriyaH jJAnam",
+            ),
+            (Devanagari, Iast, "संस्कृतम् नमस्कृत्य क्षत्रियः ज्ञानम्"),
+            (Devanagari, Kannada, "संस्कृतम् नमस्कृत्य क्षत्रियः ज्ञानम्"),
+            (Iast, Slp1, "saṃskṛtam namaskṛtya kṣatriyaḥ jñānam"),
+        ];
+        for (from, to, input) in cases {
+            let m = Mapping::new(*from, *to);
+            assert_eq!(
+                apply_icu_rules(&m.to_icu_rules(), input),
+                transliterate(input, &m),
+                "{from:?} > {to:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn to_icu_rules_alphabet_to_abugida() {
+        let m = Mapping::new(HarvardKyoto, Devanagari);
+        let rules = m.to_icu_rules();
+        assert!(rules.starts_with("# Generated by vidyut-lipi: HarvardKyoto > Devanagari\n"));
+        assert!(rules.contains("\nka > क ;\n"));
+        assert!(rules.contains("\nkhi > खि ;\n"));
+        assert!(rules.contains("\nk > क् ;\n"));
+        assert!(rules.contains("\nA > आ ;\n"));
+
+        // Longer rules come first.
+        let i_kha = rules.find("\nkha > ").expect("present");
+        let i_kh = rules.find("\nkh > ").expect("present");
+        let i_k = rules.find("\nk > ").expect("present");
+        assert!(i_kha < i_kh && i_kh < i_k);
+    }
+
+    #[test]
+    fn to_icu_rules_abugida_to_alphabet() {
+        let m = Mapping::new(Devanagari, Iast);
+        let rules = m.to_icu_rules();
+        assert!(rules.contains("\nक > ka ;\n"));
+        assert!(rules.contains("\nक् > k ;\n"));
+        assert!(rules.contains("\nकि > ki ;\n"));
+        assert!(rules.contains("\nइ > i ;\n"));
+    }
+
+    #[test]
+    fn to_icu_rules_abugida_to_abugida() {
+        let m = Mapping::new(Devanagari, Kannada);
+        let rules = m.to_icu_rules();
+        assert!(rules.contains("\nक > ಕ ;\n"));
+        assert!(rules.contains("\nि > ಿ ;\n"));
+        assert!(rules.contains("\n् > ್ ;\n"));
+    }
+}
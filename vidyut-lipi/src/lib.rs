@@ -2,20 +2,27 @@
 #![deny(missing_docs)]
 #![deny(clippy::unwrap_used)]
 
+mod akshara;
 mod autogen_schemes;
+mod collate;
 mod detect;
 mod errors;
+mod export;
 mod lipika;
 mod mapping;
 mod numerals;
+mod profile;
 mod reshape;
 mod scheme;
 mod transliterate;
 mod unicode_norm;
 pub mod wasm;
 
+pub use akshara::{aksharas, Akshara, Aksharas};
+pub use collate::{sort_key, Collator, SortKey};
 pub use detect::detect;
-pub use lipika::Lipika;
+pub use lipika::{Lipika, SharedLipika};
 pub use mapping::Mapping;
+pub use profile::Profile;
 pub use scheme::Scheme;
 pub use transliterate::transliterate;
@@ -1,8 +1,11 @@
 //! Provides a convenient transliteration API for end users.
 
 use crate::mapping::Mapping;
+use crate::profile::Profile;
 use crate::scheme::Scheme;
 use crate::transliterate::transliterate;
+use rustc_hash::FxHashMap;
+use std::sync::{Arc, RwLock};
 
 // Size of the internal `Vec` cache. We search this cache with a linear scan, so keep this small.
 const CACHE_CAPACITY: usize = 10;
@@ -59,6 +62,8 @@ pub struct Lipika {
     //
     // Use an i32 so that we can safely overflow (if in a long-running process)
     next_stamp: i32,
+    // Output conventions to use for specific schemes.
+    profiles: Vec<Profile>,
 }
 
 impl Lipika {
@@ -67,9 +72,38 @@ impl Lipika {
         Self {
             cache: Vec::new(),
             next_stamp: 0,
+            profiles: Vec::new(),
         }
     }
 
+    /// Uses the conventions of `profile` when transliterating to `profile.scheme()`.
+    ///
+    /// If a profile already exists for `profile.scheme()`, `profile` replaces it.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use vidyut_lipi::{Lipika, Profile, Scheme};
+    ///
+    /// let mut lipika = Lipika::new()
+    ///     .with_profile(Profile::TamilPlain)
+    ///     .with_profile(Profile::MalayalamChillu);
+    ///
+    /// let tamil = lipika.transliterate("govinda", Scheme::HarvardKyoto, Scheme::Tamil);
+    /// assert_eq!(tamil, "கோவிந்த");
+    ///
+    /// let malayalam = lipika.transliterate("govindan", Scheme::HarvardKyoto, Scheme::Malayalam);
+    /// assert_eq!(malayalam, "ഗോവിന്ദൻ");
+    /// ```
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        let scheme = profile.scheme();
+        self.profiles.retain(|p| p.scheme() != scheme);
+        self.profiles.push(profile);
+        // Cached mappings for `scheme` are now stale.
+        self.cache.retain(|x| x.to != scheme);
+        self
+    }
+
     /// Transliterates the given input text.
     ///
     /// `transliterate` first checks if the mapping between `from` and `to` is available in the
@@ -121,7 +155,7 @@ impl Lipika {
                 stamp: self.next_stamp,
                 from,
                 to,
-                mapping: Mapping::new(from, to),
+                mapping: create_mapping(from, to, &self.profiles),
             };
             self.cache.push(entry);
             &self.cache.last().expect("just pushed").mapping
@@ -129,6 +163,124 @@ impl Lipika {
     }
 }
 
+/// Creates a mapping between `from` and `to` that uses the matching profile in `profiles`, if
+/// one exists.
+fn create_mapping(from: Scheme, to: Scheme, profiles: &[Profile]) -> Mapping {
+    let mapping = Mapping::new(from, to);
+    match profiles.iter().find(|p| p.scheme() == to) {
+        Some(p) => mapping.with_profile(*p),
+        None => mapping,
+    }
+}
+
+/// A thread-safe transliterator whose mapping cache is shared across threads.
+///
+/// `Lipika` requires `&mut self` for every call, so sharing one instance across threads requires
+/// a mutex that serializes all transliteration. `SharedLipika` instead takes `&self`: it holds its
+/// lock only while looking up or inserting a `Mapping`, and it transliterates outside of the lock.
+/// So once a mapping has been created, concurrent calls proceed in parallel.
+///
+/// Unlike `Lipika`, `SharedLipika` never evicts mappings. Since there is at most one mapping per
+/// pair of `Scheme`s, the cache's size is bounded.
+///
+/// ### Usage
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+/// use vidyut_lipi::{Scheme, SharedLipika};
+///
+/// let lipika = Arc::new(SharedLipika::new());
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let lipika = Arc::clone(&lipika);
+///         thread::spawn(move || {
+///             lipika.transliterate("saMskRtam", Scheme::HarvardKyoto, Scheme::Devanagari)
+///         })
+///     })
+///     .collect();
+///
+/// for h in handles {
+///     assert_eq!(h.join().unwrap(), "संस्कृतम्");
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct SharedLipika {
+    cache: RwLock<FxHashMap<(Scheme, Scheme), Arc<Mapping>>>,
+    profiles: Vec<Profile>,
+}
+
+impl SharedLipika {
+    /// Creates a new `SharedLipika` instance with an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `SharedLipika` instance whose cache contains a mapping for every pair of
+    /// `Scheme`s.
+    ///
+    /// This is useful for long-running servers that would rather pay for all mappings at startup
+    /// than when serving requests.
+    pub fn with_all_mappings() -> Self {
+        let mut cache = FxHashMap::default();
+        for from in Scheme::iter() {
+            for to in Scheme::iter() {
+                cache.insert((*from, *to), Arc::new(Mapping::new(*from, *to)));
+            }
+        }
+        Self {
+            cache: RwLock::new(cache),
+            profiles: Vec::new(),
+        }
+    }
+
+    /// Uses the conventions of `profile` when transliterating to `profile.scheme()`.
+    ///
+    /// If a profile already exists for `profile.scheme()`, `profile` replaces it.
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        let scheme = profile.scheme();
+        self.profiles.retain(|p| p.scheme() != scheme);
+        self.profiles.push(profile);
+
+        let cache = self.cache.get_mut().unwrap_or_else(|e| e.into_inner());
+        for ((_, to), mapping) in cache.iter_mut() {
+            if *to == scheme {
+                *mapping = Arc::new((**mapping).clone().with_profile(profile));
+            }
+        }
+        self
+    }
+
+    /// Transliterates the given input text.
+    ///
+    /// `transliterate` first checks if the mapping between `from` and `to` is available in the
+    /// shared cache. If the mapping exists, `transliterate` will reuse it. Otherwise,
+    /// `transliterate` will create a new mapping and store it for future use.
+    pub fn transliterate(&self, input: impl AsRef<str>, from: Scheme, to: Scheme) -> String {
+        let mapping = self.mapping(from, to);
+        transliterate(input.as_ref(), &mapping)
+    }
+
+    /// Returns the mapping between `from` and `to`, creating it if absent.
+    pub fn mapping(&self, from: Scheme, to: Scheme) -> Arc<Mapping> {
+        // The cache is never left in an inconsistent state, so we can safely ignore poisoning.
+        if let Some(m) = self
+            .cache
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&(from, to))
+        {
+            return Arc::clone(m);
+        }
+
+        // Create the mapping outside of the lock so that other threads can continue to read.
+        let mapping = Arc::new(create_mapping(from, to, &self.profiles));
+        let mut cache = self.cache.write().unwrap_or_else(|e| e.into_inner());
+        Arc::clone(cache.entry((from, to)).or_insert(mapping))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,4 +301,39 @@ mod tests {
         assert_eq!(lipika.cache.len(), CACHE_CAPACITY);
         assert_eq!(lipika.next_stamp, num_calls);
     }
+
+    #[test]
+    fn shared_lipika_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedLipika>();
+    }
+
+    #[test]
+    fn shared_lipika_across_threads() {
+        let lipika = SharedLipika::new();
+        std::thread::scope(|s| {
+            for scheme in Scheme::iter() {
+                let lipika = &lipika;
+                s.spawn(move || {
+                    let result = lipika.transliterate("saMskRtam", Scheme::HarvardKyoto, *scheme);
+                    let mut serial = Lipika::new();
+                    assert_eq!(
+                        result,
+                        serial.transliterate("saMskRtam", Scheme::HarvardKyoto, *scheme)
+                    );
+                });
+            }
+        });
+
+        let cache = lipika.cache.read().expect("not poisoned");
+        assert_eq!(cache.len(), Scheme::iter().count());
+    }
+
+    #[test]
+    fn shared_lipika_reuses_mappings() {
+        let lipika = SharedLipika::new();
+        let m1 = lipika.mapping(Scheme::HarvardKyoto, Scheme::Devanagari);
+        let m2 = lipika.mapping(Scheme::HarvardKyoto, Scheme::Devanagari);
+        assert!(Arc::ptr_eq(&m1, &m2));
+    }
 }
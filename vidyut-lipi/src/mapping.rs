@@ -1,5 +1,6 @@
 //! Creates maps between different schemes.   
 
+use crate::profile::Profile;
 use crate::scheme::Scheme;
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -40,7 +41,7 @@ pub(crate) enum TokenKind {
 }
 
 impl TokenKind {
-    fn from_devanagari_key(s: &str) -> Self {
+    pub(crate) fn from_devanagari_key(s: &str) -> Self {
         use TokenKind::*;
 
         const MARK_AA: char = '\u{093e}';
@@ -258,6 +259,11 @@ pub struct Mapping {
     pub(crate) len_longest_key: usize,
     pub(crate) numeral_to_int: FxHashMap<String, u32>,
     pub(crate) int_to_numeral: FxHashMap<u32, String>,
+
+    /// The output conventions to use, if not the default for `to`.
+    pub(crate) profile: Option<Profile>,
+    /// A helper mapping that some profiles use when reshaping their output.
+    pub(crate) profile_mapping: Option<Box<Mapping>>,
 }
 
 impl Mapping {
@@ -387,9 +393,43 @@ impl Mapping {
             len_longest_key,
             numeral_to_int,
             int_to_numeral,
+            profile: None,
+            profile_mapping: None,
         }
     }
 
+    /// Sets the output conventions for this mapping.
+    ///
+    /// `profile` takes effect only if `profile.scheme()` is the same as `self.to()`. Otherwise,
+    /// this mapping is returned unchanged.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use vidyut_lipi::{transliterate, Mapping, Profile, Scheme};
+    ///
+    /// let m = Mapping::new(Scheme::Slp1, Scheme::Malayalam).with_profile(Profile::MalayalamChillu);
+    /// assert_eq!(m.profile(), Some(Profile::MalayalamChillu));
+    /// assert_eq!(transliterate("rAman", &m), "രാമൻ");
+    /// ```
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        if profile.scheme() != self.to {
+            return self;
+        }
+
+        self.profile = Some(profile);
+        self.profile_mapping = match profile {
+            Profile::TamilGrantha => Some(Box::new(Mapping::new(Scheme::Tamil, Scheme::Grantha))),
+            _ => None,
+        };
+        self
+    }
+
+    /// The output conventions used by this mapping, if not the default for `self.to()`.
+    pub fn profile(&self) -> Option<Profile> {
+        self.profile
+    }
+
     /// The source scheme.
     pub fn from(&self) -> Scheme {
         self.from
@@ -0,0 +1,64 @@
+//! Output conventions for writing Sanskrit in regional scripts.
+
+use crate::scheme::Scheme;
+
+/// A convention for writing Sanskrit in a specific regional script.
+///
+/// Communities that write Sanskrit in Tamil, Malayalam, or Gurmukhi follow different orthographic
+/// conventions depending on their audience. By default, `vidyut-lipi` uses one convention per
+/// script. A `Profile` selects a different convention for one output `Scheme`.
+///
+/// ### Usage
+///
+/// ```
+/// use vidyut_lipi::{Lipika, Profile, Scheme};
+///
+/// let mut lipika = Lipika::new().with_profile(Profile::TamilGrantha);
+/// let tamil = lipika.transliterate("गोविन्दः", Scheme::Devanagari, Scheme::Tamil);
+/// assert_eq!(tamil, "𑌗𑍋வி𑌨𑍍𑌦𑌃");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Profile {
+    /// Tamil without superscript numerals, as in *கோவிந்த* for *govinda*.
+    ///
+    /// This convention is common in popular texts but is lossy, since it merges consonants like
+    /// *ka*, *kha*, *ga*, and *gha*.
+    TamilPlain,
+
+    /// Tamil mixed with Grantha, as in *𑌗𑍋வி𑌨𑍍𑌦* for *govinda*.
+    ///
+    /// Aksharas that Tamil can't express on its own (those with voiced or aspirated consonants or
+    /// with vocalic *r* and *l*) are written in the Grantha script. All other aksharas, including
+    /// those with *ja*, *śa*, *ṣa*, *sa*, and *ha*, use the Tamil script.
+    TamilGrantha,
+
+    /// Malayalam with chillu letters, as in *രാമൻ* for *rāman*.
+    ///
+    /// A dead consonant at the end of a word is written with its chillu form if one exists.
+    MalayalamChillu,
+
+    /// Gurmukhi with addak, as in *ਸਿੱਧ* for *siddha*.
+    ///
+    /// A geminate consonant is written with addak instead of a virama. This also applies when an
+    /// unaspirated consonant precedes its aspirated counterpart.
+    GurmukhiAddak,
+}
+
+impl Profile {
+    /// Returns an iterator over all available `Profile`s.
+    pub fn iter() -> impl Iterator<Item = &'static Profile> {
+        use Profile::*;
+        const PROFILES: &[Profile] = &[TamilPlain, TamilGrantha, MalayalamChillu, GurmukhiAddak];
+        PROFILES.iter()
+    }
+
+    /// Returns the output scheme that this profile applies to.
+    pub fn scheme(&self) -> Scheme {
+        use Profile::*;
+        match self {
+            TamilPlain | TamilGrantha => Scheme::Tamil,
+            MalayalamChillu => Scheme::Malayalam,
+            GurmukhiAddak => Scheme::Gurmukhi,
+        }
+    }
+}
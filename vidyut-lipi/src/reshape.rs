@@ -22,7 +22,11 @@
 //! crates greatly increase the binary size of `vidyu-lipi`, which has serving implications for
 //! WASM. So instead of using regexes, we've rolled our own logic for these transformations.
 
+use crate::akshara::aksharas;
+use crate::mapping::Mapping;
+use crate::profile::Profile;
 use crate::scheme::Scheme;
+use crate::transliterate::transliterate;
 use crate::unicode_norm;
 
 const BENGALI_LETTER_YA: char = '\u{09af}';
@@ -38,6 +42,12 @@ const BENGALI_VIRAMA: char = '\u{09cd}';
 /// Used instead of space (' ') in Bhaiksuki.
 const BHAIKSUKI_WORD_SEPARATOR: char = '\u{11c43}';
 
+/// Doubles the consonant that follows it.
+const GURMUKHI_ADDAK: char = '\u{0a71}';
+
+/// Gurmukhi virama.
+const GURMUKHI_SIGN_VIRAMA: char = '\u{0a4d}';
+
 /// Javanese virama.
 const JAVANESE_PANGKON: char = '\u{a9c0}';
 
@@ -202,6 +212,24 @@ fn to_cham_final_consonant(c: char) -> Option<char> {
     Some(ret)
 }
 
+/// Returns whether `x` followed by `y` is a geminate consonant, as written with an addak.
+fn is_gurmukhi_geminate(x: char, y: char) -> bool {
+    const CONSONANTS: std::ops::RangeInclusive<char> = '\u{0a15}'..='\u{0a39}';
+    const UNASPIRATED_ASPIRATED: &[(char, char)] = &[
+        ('\u{0a15}', '\u{0a16}'), // ka kha
+        ('\u{0a17}', '\u{0a18}'), // ga gha
+        ('\u{0a1a}', '\u{0a1b}'), // ca cha
+        ('\u{0a1c}', '\u{0a1d}'), // ja jha
+        ('\u{0a1f}', '\u{0a20}'), // Ta Tha
+        ('\u{0a21}', '\u{0a22}'), // Da Dha
+        ('\u{0a24}', '\u{0a25}'), // ta tha
+        ('\u{0a26}', '\u{0a27}'), // da dha
+        ('\u{0a2a}', '\u{0a2b}'), // pa pha
+        ('\u{0a2c}', '\u{0a2d}'), // ba bha
+    ];
+    CONSONANTS.contains(&x) && (x == y || UNASPIRATED_ASPIRATED.contains(&(x, y)))
+}
+
 fn is_gunjala_gondi_consonant(c: char) -> bool {
     ('\u{11d6c}'..='\u{11d89}').contains(&c)
 }
@@ -277,6 +305,23 @@ fn to_limbu_final_consonant(c: char) -> Option<char> {
     Some(ret)
 }
 
+fn to_malayalam_chillu(c: char) -> Option<char> {
+    let ret = match c {
+        '\u{0d23}' => '\u{0d7a}', // nna
+        '\u{0d28}' => '\u{0d7b}', // na
+        '\u{0d30}' => '\u{0d7c}', // ra
+        '\u{0d32}' => '\u{0d7d}', // la
+        '\u{0d33}' => '\u{0d7e}', // lla
+        '\u{0d15}' => '\u{0d7f}', // ka
+        _ => return None,
+    };
+    Some(ret)
+}
+
+fn is_malayalam_sound(c: char) -> bool {
+    ('\u{0d00}'..='\u{0d63}').contains(&c) || ('\u{0d7a}'..='\u{0d7f}').contains(&c)
+}
+
 fn is_masaram_gondi_consonant(c: char) -> bool {
     ('\u{11d0c}'..='\u{11d30}').contains(&c)
         || c == MASARAM_GONDI_REPHA
@@ -1075,3 +1120,75 @@ pub fn reshape_after(output: String, to: Scheme) -> String {
         _ => output,
     }
 }
+
+/// Reshapes `output` to follow the conventions of `profile`.
+///
+/// We run this function before `reshape_after` so that `output` still has a simple code point
+/// order.
+pub fn reshape_for_profile(output: String, profile: Profile, mapping: &Mapping) -> String {
+    const MALAYALAM_SIGN_VIRAMA: char = '\u{0d4d}';
+
+    let mut m = Matcher::new(&output);
+    match profile {
+        Profile::TamilPlain => {
+            while m.not_empty() {
+                if m.match_1(is_tamil_superscript) {
+                    m.take_1(|_, _| {});
+                } else {
+                    m.push_next();
+                }
+            }
+            m.finish()
+        }
+        Profile::TamilGrantha => {
+            let grantha = match &mapping.profile_mapping {
+                Some(m) => m,
+                None => return output,
+            };
+
+            // Write in Grantha any akshara whose consonants or vowel can't be expressed in Tamil.
+            // Such aksharas use superscript numerals or (for vocalic r and l) a trailing U+02BC.
+            let needs_grantha = |text: &str| {
+                let text = text
+                    .trim_end_matches("ம்ʼ")
+                    .trim_end_matches("ம்ˮ")
+                    .trim_end_matches('꞉');
+                text.contains(|c| is_tamil_superscript(c) || c == '\u{02bc}')
+            };
+
+            let mut buf = String::with_capacity(output.len());
+            for akshara in aksharas(&output, Scheme::Tamil) {
+                if needs_grantha(akshara.text()) {
+                    buf.push_str(&transliterate(akshara.text(), grantha));
+                } else {
+                    buf.push_str(akshara.text());
+                }
+            }
+            buf
+        }
+        Profile::MalayalamChillu => {
+            while m.not_empty() {
+                // (cons) + virama + (end / non-sound) --> (chillu) + (end / non-sound)
+                if m.match_2(|x, y| to_malayalam_chillu(x).is_some() && y == MALAYALAM_SIGN_VIRAMA)
+                    && !matches!(m.slice().chars().nth(2), Some(z) if is_malayalam_sound(z))
+                {
+                    m.take_2(|buf, x, _| buf.push(to_malayalam_chillu(x).expect("chillu")));
+                } else {
+                    m.push_next();
+                }
+            }
+            m.finish()
+        }
+        Profile::GurmukhiAddak => {
+            while m.not_empty() {
+                // (cons) + virama + (same cons) --> addak + (same cons)
+                if m.match_3(|x, y, z| y == GURMUKHI_SIGN_VIRAMA && is_gurmukhi_geminate(x, z)) {
+                    m.take_3(|buf, _, _, z| buf.extend(&[GURMUKHI_ADDAK, z]));
+                } else {
+                    m.push_next();
+                }
+            }
+            m.finish()
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::mapping::Mapping;
 use crate::numerals;
-use crate::reshape::{reshape_after, reshape_before};
+use crate::reshape::{reshape_after, reshape_before, reshape_for_profile};
 use crate::scheme::Scheme;
 
 /// Transliterates the input string with the provided `Mapping`.
@@ -37,7 +37,8 @@ pub fn transliterate(input: impl AsRef<str>, mapping: &Mapping) -> String {
 ///
 /// 3. *Post-processing.* Certain schemes use a code point order that is difficult to create just
 ///    by remapping. So, we reshape the output of (2) to create our final output. For details, see
-///    `reshape_after`.
+///    `reshape_after`. If `mapping` has a `Profile`, we also apply its conventions here. For
+///    details, see `reshape_for_profile`.
 ///
 /// Each of these stages makes at most one pass over the input string. Several scheme pairs will be
 /// able to avoid doing work in stages (1) and (3) and thus process the input text in just one
@@ -164,6 +165,10 @@ fn transliterate_inner(input: &str, mapping: &Mapping) -> String {
         i = next_i;
     }
 
+    let output = match mapping.profile {
+        Some(profile) => reshape_for_profile(output, profile, mapping),
+        None => output,
+    };
     reshape_after(output, mapping.to())
 }
 
@@ -1,5 +1,5 @@
 use vidyut_lipi::Scheme::*;
-use vidyut_lipi::{Lipika, Scheme};
+use vidyut_lipi::{transliterate, Lipika, Mapping, Profile, Scheme, SharedLipika};
 
 /// A quick alias for transliterating.
 fn t(input: &str, from: Scheme, to: Scheme) -> String {
@@ -1058,7 +1058,8 @@ fn khmer_sign_robat() {
 
 #[test]
 fn malayalam_chillus() {
-    // Our chillu support is currently limited to when we transliterate *from* Malayalam.
+    // By default, we use chillus only when we transliterate *from* Malayalam. For chillu output,
+    // see `profile_malayalam_chillu`.
     let pairs = &[
         ("അൺക", "aRka", "अण्क"),
         ("അൻക", "anka", "अन्क"),
@@ -1174,3 +1175,72 @@ fn velthuis_basic() {
     // Extended consonants
     assert_has("qa .kha .ga za Ra Rha fa", "क़ ख़ ग़ ज़ ड़ ढ़ फ़");
 }
+
+// Output profiles
+// ---------------
+
+/// Asserts that `input` produces `expected` when transliterated from `from` to `profile.scheme()`
+/// with the given `profile`.
+fn assert_profile(input: &str, from: Scheme, profile: Profile, expected: &str) {
+    let mut lipika = Lipika::new().with_profile(profile);
+    let actual = lipika.transliterate(input, from, profile.scheme());
+    assert_eq!(expected, actual, "{input} ({profile:?})");
+
+    let mapping = Mapping::new(from, profile.scheme()).with_profile(profile);
+    assert_eq!(
+        expected,
+        transliterate(input, &mapping),
+        "{input} ({profile:?})"
+    );
+}
+
+#[test]
+fn profile_tamil_plain() {
+    assert_profile("गोविन्दः", Devanagari, Profile::TamilPlain, "கோவிந்த꞉");
+    assert_profile("BagavadgItA", Slp1, Profile::TamilPlain, "பகவத்கீதா");
+}
+
+#[test]
+fn profile_tamil_grantha() {
+    let p = Profile::TamilGrantha;
+    assert_profile("gItA", Slp1, p, "𑌗𑍀தா");
+    assert_profile("kfzRa", Slp1, p, "𑌕𑍃ஷ்ண");
+    assert_profile("saMskftam", Slp1, p, "ஸம்ʼ𑌸𑍍𑌕𑍃தம்");
+    // Tamil-only aksharas are unchanged.
+    assert_profile("jayati sa haris", Slp1, p, "ஜயதி ஸ ஹரிஸ்");
+}
+
+#[test]
+fn profile_malayalam_chillu() {
+    let p = Profile::MalayalamChillu;
+    assert_profile("rAman", Slp1, p, "രാമൻ");
+    assert_profile("vidvAn Bavet", Slp1, p, "വിദ്വാൻ ഭവേത്");
+    assert_profile("aRkaH kAl", Slp1, p, "അണ്കഃ കാൽ");
+    // Chillus only at the end of a word.
+    assert_profile("rAmena", Slp1, p, "രാമേന");
+    assert_profile("ananta", Slp1, p, "അനന്ത");
+}
+
+#[test]
+fn profile_gurmukhi_addak() {
+    let p = Profile::GurmukhiAddak;
+    assert_profile("sidDa", Slp1, p, "ਸਿੱਧ");
+    assert_profile("pitta", Slp1, p, "ਪਿੱਤ");
+    assert_profile("satya", Slp1, p, "ਸਤ੍ਯ");
+}
+
+#[test]
+fn profile_for_other_scheme_has_no_effect() {
+    let mapping = Mapping::new(Slp1, Devanagari).with_profile(Profile::TamilPlain);
+    assert_eq!(mapping.profile(), None);
+
+    let mut lipika = Lipika::new().with_profile(Profile::GurmukhiAddak);
+    assert_eq!(lipika.transliterate("sidDa", Slp1, Devanagari), "सिद्ध");
+}
+
+#[test]
+fn profile_with_shared_lipika() {
+    let lipika = SharedLipika::with_all_mappings().with_profile(Profile::MalayalamChillu);
+    assert_eq!(lipika.transliterate("rAman", Slp1, Malayalam), "രാമൻ");
+    assert_eq!(lipika.transliterate("rAman", Slp1, Tamil), "ராமந்");
+}
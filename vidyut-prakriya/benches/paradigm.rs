@@ -0,0 +1,140 @@
+//! Compares deriving tinantas one cell at a time against deriving them as paradigms.
+//!
+//! Usage:
+//!
+//! ```text
+//! cargo bench -p vidyut-prakriya --bench paradigm -- --step 20 --runs 5
+//! ```
+//!
+//! Both strategies run on a single thread so that the comparison measures shared work rather than
+//! parallelism.
+use clap::Parser;
+use std::error::Error;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use vidyut_prakriya::args::{Dhatu, Lakara, Prayoga, Purusha, Sanadi, Tinanta, Vacana};
+use vidyut_prakriya::{Dhatupatha, Vyakarana};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about)]
+struct Args {
+    /// Use every `step`-th dhatu in the Dhatupatha.
+    #[arg(long, default_value_t = 20)]
+    step: usize,
+    /// The number of times to run each strategy. We report the fastest run.
+    #[arg(long, default_value_t = 5)]
+    runs: usize,
+    /// Ignored
+    #[arg(long)]
+    bench: bool,
+}
+
+type Cells = Vec<Vec<String>>;
+
+/// Derives each tinanta with its own call to `derive_tinantas`.
+fn derive_by_cell(v: &Vyakarana, dhatus: &[Dhatu], prayoga: Prayoga) -> Cells {
+    let mut ret = Vec::new();
+    for dhatu in dhatus {
+        for lakara in Lakara::iter().filter(|la| **la != Lakara::Let) {
+            for purusha in Purusha::iter() {
+                for vacana in Vacana::iter() {
+                    let args = Tinanta::new(dhatu.clone(), prayoga, *lakara, *purusha, *vacana);
+                    let mut texts: Vec<_> =
+                        v.derive_tinantas(&args).iter().map(|p| p.text()).collect();
+                    texts.sort();
+                    ret.push(texts);
+                }
+            }
+        }
+    }
+    ret
+}
+
+/// Derives all tinantas for each dhatu with a single call to `derive_tinanta_paradigm`.
+fn derive_by_paradigm(v: &Vyakarana, dhatus: &[Dhatu], prayoga: Prayoga) -> Cells {
+    let mut ret = Vec::new();
+    for dhatu in dhatus {
+        let paradigm = v.derive_tinanta_paradigm(dhatu, prayoga, None);
+        for table in paradigm.tables() {
+            for purusha in Purusha::iter() {
+                for vacana in Vacana::iter() {
+                    let mut texts: Vec<_> = table
+                        .get(*purusha, *vacana)
+                        .iter()
+                        .map(|p| p.text())
+                        .collect();
+                    texts.sort();
+                    ret.push(texts);
+                }
+            }
+        }
+    }
+    ret
+}
+
+/// Runs `a` and `b` `runs` times each, alternating between them, and returns the output and
+/// fastest running time of each.
+///
+/// We interleave runs and keep the minimum so that other load on the machine affects both
+/// strategies equally and affects the reported times as little as possible.
+fn time_both(
+    runs: usize,
+    a: impl Fn() -> Cells,
+    b: impl Fn() -> Cells,
+) -> ((Cells, Duration), (Cells, Duration)) {
+    let mut ret_a = (Vec::new(), Duration::MAX);
+    let mut ret_b = (Vec::new(), Duration::MAX);
+    for _ in 0..runs {
+        for (f, ret) in [(&a as &dyn Fn() -> Cells, &mut ret_a), (&b, &mut ret_b)] {
+            let start = Instant::now();
+            ret.0 = black_box(f());
+            ret.1 = ret.1.min(start.elapsed());
+        }
+    }
+    (ret_a, ret_b)
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    let dhatupatha = Dhatupatha::from_path("data/dhatupatha.tsv")?;
+    let v = Vyakarana::builder().log_steps(false).build();
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(1).build()?;
+
+    let workloads: &[(&str, &[Sanadi], Prayoga)] = &[
+        ("basic kartari", &[], Prayoga::Kartari),
+        ("san kartari", &[Sanadi::san], Prayoga::Kartari),
+        ("Ric karmani", &[Sanadi::Ric], Prayoga::Karmani),
+    ];
+    for (name, sanadi, prayoga) in workloads {
+        let dhatus: Vec<Dhatu> = dhatupatha
+            .iter()
+            .step_by(args.step)
+            .map(|e| e.dhatu().clone().with_sanadi(sanadi))
+            .collect();
+
+        let ((by_cell, t_cell), (by_paradigm, t_paradigm)) = pool.install(|| {
+            time_both(
+                args.runs,
+                || derive_by_cell(&v, &dhatus, *prayoga),
+                || derive_by_paradigm(&v, &dhatus, *prayoga),
+            )
+        });
+        assert_eq!(by_cell, by_paradigm, "{name}: results differ");
+
+        println!(
+            "{name} ({} dhatus): by cell {:.2?}, by paradigm {:.2?} ({:.2}x)",
+            dhatus.len(),
+            t_cell,
+            t_paradigm,
+            t_cell.as_secs_f64() / t_paradigm.as_secs_f64(),
+        );
+    }
+    Ok(())
+}
+
+fn main() {
+    let args = Args::parse();
+    if let Err(err) = run(args) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,85 @@
+#!/usr/bin/env python3
+"""Adds a `sources` column to `data/dhatupatha.tsv`.
+
+Usage:
+
+    python3 scripts/add_dhatu_sources.py path/to/data.txt
+
+where `data.txt` is the dhatu JSON file from the ashtadhyayi.com data
+repository (https://github.com/ashtadhyayi-com/data/blob/master/dhatu/data.txt).
+
+We keep the code, dhatu, and artha columns of `dhatupatha.tsv` as-is, since
+they include svaras and corrections that the JSON file lacks. We join on the
+dhatu code and add one column that lists the sources that attest each dhatu
+(e.g. `kaumudi,madhaviya`). Entries with no sources get `-`.
+"""
+import json
+import sys
+from pathlib import Path
+
+base = Path(__file__).parent.parent
+TSV_PATH = base / "data/dhatupatha.tsv"
+
+# Maps each `Source` code in `src/dhatupatha.rs` to the JSON field that holds
+# the dhatu's index in that source. A dhatu is attested in a source if this
+# field is non-empty.
+SOURCE_FIELDS = {
+    "kaumudi": "kaumudi",
+    "kusumakara": "kusumakara",
+    "madhaviya": "madhaviya",
+    "kshiratarangini": "kshiratarangini",
+    "dhatupradipa": "dhatupradipa",
+}
+
+# The JSON field that holds the dhatu's code, e.g. `01.0001`.
+CODE_FIELD = "baseindex"
+
+
+def normalize_code(code: str) -> str:
+    gana, number = code.split(".")
+    return f"{int(gana):02}.{int(number):04}"
+
+
+def is_attested(value) -> bool:
+    return value not in (None, "", "-", "0", 0)
+
+
+def read_sources(json_path: Path) -> dict[str, str]:
+    with open(json_path) as f:
+        data = json.load(f)
+    items = data["data"] if isinstance(data, dict) else data
+
+    missing = [f for f in [CODE_FIELD, *SOURCE_FIELDS.values()] if f not in items[0]]
+    if missing:
+        keys = ", ".join(sorted(items[0]))
+        sys.exit(f"Missing fields {missing} in {json_path}. Available fields: {keys}")
+
+    sources = {}
+    for item in items:
+        code = normalize_code(item[CODE_FIELD])
+        attested = [s for s, field in SOURCE_FIELDS.items() if is_attested(item[field])]
+        sources[code] = ",".join(attested) or "-"
+    return sources
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(__doc__)
+    sources = read_sources(Path(sys.argv[1]))
+
+    lines = []
+    with open(TSV_PATH) as f:
+        for i, line in enumerate(f):
+            fields = line.rstrip("\n").split("\t")[:3]
+            if i == 0:
+                fields.append("sources")
+            else:
+                fields.append(sources.get(fields[0], "-"))
+            lines.append("\t".join(fields))
+
+    with open(TSV_PATH, "w") as f:
+        f.write("\n".join(lines) + "\n")
+
+
+if __name__ == "__main__":
+    main()
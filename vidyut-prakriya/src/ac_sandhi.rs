@@ -306,6 +306,18 @@ fn apply_ac_sandhi_at_term_boundary(p: &mut Prakriya, i: usize) -> Option<()> {
         p.run_at("6.1.69", j, op::lopa);
     }
 
+    let x = p.get(i)?;
+    let y = p.get(j)?;
+    if y.is_sup() && y.has_u("Si") && p.is_chandasi() {
+        // vanA, viSvA
+        //
+        // TODO: support other stems (e.g. with num-Agama before a final consonant). For now, we
+        // require a final num-Agama so that 8.2.7 applies.
+        if x.has_tag(T::FlagNum) && x.has_antya('n') {
+            p.optional_run_at("6.1.70", j, op::lopa);
+        }
+    }
+
     let mut had_vrddhi = false;
     let x = p.get(i)?;
     let y = p.get(j)?;
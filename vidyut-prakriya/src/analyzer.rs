@@ -0,0 +1,390 @@
+/*!
+Maps a surface form back to the arguments that derive it.
+
+`Vyakarana` runs in one direction: it accepts some arguments and returns the words they derive.
+`Analyzer` runs in the other direction. Given some word in SLP1, it returns every argument set that
+derives that word along with the prakriyas for each of those arguments.
+
+Our approach is simple: we exhaustively generate all words for some set of dhatus and
+pratipadikas, store the results in an index, and look up words in that index. A guided backward
+search would be faster to construct, but exhaustive generation guarantees that our analyses agree
+exactly with our derivations.
+*/
+use crate::args::{
+    BaseKrt, Dhatu, Krdanta, Lakara, Linga, Pratipadika, Prayoga, Purusha, Subanta, Taddhita,
+    Taddhitanta, Tinanta, Vacana, Vibhakti,
+};
+use crate::ashtadhyayi;
+use crate::core::prakriya_stack::PrakriyaStack;
+use crate::core::Prakriya;
+use crate::dhatupatha::Dhatupatha;
+use crate::sounds::{s, Set};
+use crate::vyakarana::Vyakarana;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref M_EC: Set = s("m ec");
+}
+
+/// The arguments that derive some analyzed word.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum AnalysisArgs {
+    /// A verb.
+    Tinanta(Tinanta),
+    /// A nominal word, including inflected krdantas and taddhitantas.
+    Subanta(Subanta),
+    /// An uninflected krdanta, e.g. *gatvA* or *gatavat*.
+    Krdanta(Krdanta),
+    /// An uninflected taddhitanta, e.g. *Opagava*.
+    Taddhitanta(Taddhitanta),
+}
+
+/// One possible analysis of some word.
+#[derive(Debug)]
+pub struct Analysis {
+    args: AnalysisArgs,
+    prakriyas: Vec<Prakriya>,
+}
+
+impl Analysis {
+    /// The arguments that derive the word.
+    pub fn args(&self) -> &AnalysisArgs {
+        &self.args
+    }
+
+    /// All prakriyas for `args` whose output is the word.
+    pub fn prakriyas(&self) -> &Vec<Prakriya> {
+        &self.prakriyas
+    }
+}
+
+/// Finds the arguments that derive some word.
+///
+/// `Analyzer` builds its index when it is created, which is expensive for large inputs. For
+/// example, indexing all tinantas for the full Dhatupatha requires several hundred thousand
+/// derivations. For faster startup, restrict the dhatus and pratyayas that the analyzer uses.
+///
+/// ### Example
+///
+/// ```
+/// # use vidyut_prakriya::{AnalysisArgs, Analyzer};
+/// # use vidyut_prakriya::args::*;
+/// let analyzer = Analyzer::builder()
+///     .dhatus(&[Dhatu::mula("BU", Gana::Bhvadi)])
+///     .krts(&[BaseKrt::kta])
+///     .build();
+///
+/// let analyses = analyzer.analyze("Bavati");
+/// assert!(analyses.iter().any(|a| match a.args() {
+///     AnalysisArgs::Tinanta(t) => t.lakara() == Lakara::Lat,
+///     _ => false,
+/// }));
+/// assert_eq!(analyses[0].prakriyas()[0].text(), "Bavati");
+/// ```
+#[derive(Debug)]
+pub struct Analyzer {
+    vyakarana: Vyakarana,
+    index: HashMap<String, Vec<AnalysisArgs>>,
+}
+
+impl Analyzer {
+    /// Returns a builder that controls which words the analyzer can recognize.
+    pub fn builder() -> AnalyzerBuilder {
+        AnalyzerBuilder::new()
+    }
+
+    /// Returns all analyses of the given SLP1 word.
+    ///
+    /// Analyses are returned in the order in which the analyzer indexed them. Entries for each
+    /// dhatu come first, in dhatu order: that dhatu's tinantas, then each of its krdantas followed
+    /// by that krdanta's subantas. Entries for the builder's pratipadikas and their taddhitantas
+    /// come last. If the analyzer doesn't recognize `word`, this method returns an empty `Vec`.
+    pub fn analyze(&self, word: &str) -> Vec<Analysis> {
+        let candidates = match self.index.get(word) {
+            Some(x) => x,
+            None => return Vec::new(),
+        };
+
+        let mut ret = Vec::new();
+        for args in candidates {
+            let mut prakriyas = match args {
+                AnalysisArgs::Tinanta(t) => self.vyakarana.derive_tinantas(t),
+                AnalysisArgs::Subanta(s) => self.vyakarana.derive_subantas(s),
+                AnalysisArgs::Krdanta(k) => self.vyakarana.derive_krdantas(k),
+                AnalysisArgs::Taddhitanta(t) => self.vyakarana.derive_taddhitantas(t),
+            };
+            prakriyas.retain(|p| p.text() == word);
+            if !prakriyas.is_empty() {
+                ret.push(Analysis {
+                    args: args.clone(),
+                    prakriyas,
+                });
+            }
+        }
+        ret
+    }
+
+    /// Returns whether the analyzer recognizes the given SLP1 word.
+    pub fn contains(&self, word: &str) -> bool {
+        self.index.contains_key(word)
+    }
+
+    /// Returns the number of distinct words the analyzer recognizes.
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Returns whether the analyzer recognizes no words at all.
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+}
+
+/// A builder for creating an `Analyzer` struct.
+pub struct AnalyzerBuilder {
+    dhatus: Vec<Dhatu>,
+    pratipadikas: Vec<(Pratipadika, Linga)>,
+    prayogas: Vec<Prayoga>,
+    lakaras: Vec<Lakara>,
+    krts: Vec<BaseKrt>,
+    taddhitas: Vec<Taddhita>,
+    log_steps: bool,
+    is_chandasi: bool,
+}
+
+impl AnalyzerBuilder {
+    /// Creates a new builder.
+    fn new() -> Self {
+        Self {
+            dhatus: Vec::new(),
+            pratipadikas: Vec::new(),
+            prayogas: vec![Prayoga::Kartari, Prayoga::Karmani],
+            lakaras: Lakara::iter().copied().collect(),
+            krts: BaseKrt::iter().copied().collect(),
+            taddhitas: Vec::new(),
+            log_steps: true,
+            is_chandasi: false,
+        }
+    }
+
+    /// Adds every dhatu in the given Dhatupatha.
+    pub fn dhatupatha(mut self, dhatupatha: &Dhatupatha) -> Self {
+        self.dhatus
+            .extend(dhatupatha.iter().map(|entry| entry.dhatu().clone()));
+        self
+    }
+
+    /// Adds the given dhatus.
+    pub fn dhatus(mut self, dhatus: &[Dhatu]) -> Self {
+        self.dhatus.extend(dhatus.iter().cloned());
+        self
+    }
+
+    /// Adds the given pratipadikas, each of which is inflected in the given linga.
+    pub fn pratipadikas(mut self, pratipadikas: &[(Pratipadika, Linga)]) -> Self {
+        self.pratipadikas.extend(pratipadikas.iter().cloned());
+        self
+    }
+
+    /// *(default: kartari and karmani)* Sets the prayogas to use for tinantas.
+    pub fn prayogas(mut self, prayogas: &[Prayoga]) -> Self {
+        self.prayogas = prayogas.to_vec();
+        self
+    }
+
+    /// *(default: all lakaras)* Sets the lakaras to use for tinantas.
+    pub fn lakaras(mut self, lakaras: &[Lakara]) -> Self {
+        self.lakaras = lakaras.to_vec();
+        self
+    }
+
+    /// *(default: all krts)* Sets the krt-pratyayas to add to each dhatu.
+    ///
+    /// Each krdanta is indexed both as a bare stem and as a subanta in all three lingas, unless
+    /// the krdanta is an avyaya.
+    pub fn krts(mut self, krts: &[BaseKrt]) -> Self {
+        self.krts = krts.to_vec();
+        self
+    }
+
+    /// *(default: none)* Sets the taddhita-pratyayas to add to each pratipadika.
+    ///
+    /// Each taddhitanta is indexed both as a bare stem and as a subanta in all three lingas.
+    pub fn taddhitas(mut self, taddhitas: &[Taddhita]) -> Self {
+        self.taddhitas = taddhitas.to_vec();
+        self
+    }
+
+    /// *(default: true)* Controls whether the prakriyas returned by `analyze` log their steps.
+    pub fn log_steps(mut self, value: bool) -> Self {
+        self.log_steps = value;
+        self
+    }
+
+    /// *(default: false)* Controls whether the analyzer uses rules marked "chandasi," "mantre,"
+    /// etc.
+    pub fn is_chandasi(mut self, value: bool) -> Self {
+        self.is_chandasi = value;
+        self
+    }
+
+    /// Creates an `Analyzer` and builds its index.
+    pub fn build(self) -> Analyzer {
+        let generator = Generator {
+            is_chandasi: self.is_chandasi,
+        };
+
+        let mut entries: Vec<(String, AnalysisArgs)> = self
+            .dhatus
+            .par_iter()
+            .flat_map_iter(|dhatu| {
+                let mut ret = generator.tinantas(dhatu, &self.prayogas, &self.lakaras);
+                for krt in &self.krts {
+                    let krdanta = Krdanta::new(dhatu.clone(), *krt);
+                    ret.extend(generator.krdantas(krdanta));
+                }
+                ret
+            })
+            .collect();
+
+        let pratipadika_entries: Vec<(String, AnalysisArgs)> = self
+            .pratipadikas
+            .par_iter()
+            .flat_map_iter(|(pratipadika, linga)| {
+                let mut ret = generator.subantas(pratipadika, &[*linga]);
+                for taddhita in &self.taddhitas {
+                    let args = Taddhitanta::builder()
+                        .pratipadika(pratipadika.clone())
+                        .taddhita(*taddhita)
+                        .build();
+                    if let Ok(args) = args {
+                        ret.extend(generator.taddhitantas(args));
+                    }
+                }
+                ret
+            })
+            .collect();
+        entries.extend(pratipadika_entries);
+
+        let mut index: HashMap<String, Vec<AnalysisArgs>> = HashMap::new();
+        for (text, args) in entries {
+            let values = index.entry(text).or_default();
+            if !values.contains(&args) {
+                values.push(args);
+            }
+        }
+
+        let vyakarana = Vyakarana::builder()
+            .log_steps(self.log_steps)
+            .is_chandasi(self.is_chandasi)
+            .build();
+        Analyzer { vyakarana, index }
+    }
+}
+
+/// Generates (word, args) pairs for the analyzer's index.
+struct Generator {
+    is_chandasi: bool,
+}
+
+impl Generator {
+    fn find_all(
+        &self,
+        derive: impl Fn(Prakriya) -> crate::core::errors::Result<Prakriya>,
+    ) -> Vec<Prakriya> {
+        let mut stack = PrakriyaStack::new(false, self.is_chandasi, false);
+        stack.find_all(derive);
+        stack.prakriyas()
+    }
+
+    fn tinantas(
+        &self,
+        dhatu: &Dhatu,
+        prayogas: &[Prayoga],
+        lakaras: &[Lakara],
+    ) -> Vec<(String, AnalysisArgs)> {
+        let mut ret = Vec::new();
+        for prayoga in prayogas {
+            for lakara in lakaras {
+                for purusha in Purusha::iter() {
+                    for vacana in Vacana::iter() {
+                        let args =
+                            Tinanta::new(dhatu.clone(), *prayoga, *lakara, *purusha, *vacana);
+                        for p in self.find_all(|p| ashtadhyayi::derive_tinanta(p, &args)) {
+                            ret.push((p.text(), AnalysisArgs::Tinanta(args.clone())));
+                        }
+                    }
+                }
+            }
+        }
+        ret
+    }
+
+    fn krdantas(&self, args: Krdanta) -> Vec<(String, AnalysisArgs)> {
+        let prakriyas = self.find_all(|p| ashtadhyayi::derive_krdanta(p, &args));
+        if prakriyas.is_empty() {
+            return Vec::new();
+        }
+
+        let is_avyaya = prakriyas.iter().all(is_avyaya);
+        let mut ret: Vec<_> = prakriyas
+            .iter()
+            .map(|p| (p.text(), AnalysisArgs::Krdanta(args.clone())))
+            .collect();
+        if !is_avyaya {
+            let pratipadika = Pratipadika::from(args);
+            ret.extend(self.subantas(&pratipadika, &[Linga::Pum, Linga::Stri, Linga::Napumsaka]));
+        }
+        ret
+    }
+
+    fn taddhitantas(&self, args: Taddhitanta) -> Vec<(String, AnalysisArgs)> {
+        let prakriyas = self.find_all(|p| ashtadhyayi::derive_taddhitanta(p, &args));
+        if prakriyas.is_empty() {
+            return Vec::new();
+        }
+
+        let is_avyaya = prakriyas.iter().all(is_avyaya);
+        let mut ret: Vec<_> = prakriyas
+            .iter()
+            .map(|p| (p.text(), AnalysisArgs::Taddhitanta(args.clone())))
+            .collect();
+        if !is_avyaya {
+            let pratipadika = Pratipadika::from(args);
+            ret.extend(self.subantas(&pratipadika, &[Linga::Pum, Linga::Stri, Linga::Napumsaka]));
+        }
+        ret
+    }
+
+    fn subantas(&self, pratipadika: &Pratipadika, lingas: &[Linga]) -> Vec<(String, AnalysisArgs)> {
+        let mut ret = Vec::new();
+        for linga in lingas {
+            for vibhakti in Vibhakti::iter() {
+                for vacana in Vacana::iter() {
+                    let args = Subanta::new(pratipadika.clone(), *linga, *vibhakti, *vacana);
+                    for p in self.find_all(|p| ashtadhyayi::derive_subanta(p, &args)) {
+                        ret.push((p.text(), AnalysisArgs::Subanta(args.clone())));
+                    }
+                }
+            }
+        }
+        ret
+    }
+}
+
+/// Returns whether the given prakriya created an avyaya.
+///
+/// Our derivations don't yet apply the avyaya-samjna to krdantas, so we also check the conditions
+/// from rules 1.1.39 and 1.1.40 directly.
+fn is_avyaya(p: &Prakriya) -> bool {
+    match p.terms().last() {
+        Some(t) => {
+            t.is_avyaya()
+                || (t.is_krt()
+                    && (t.has_antya(&*M_EC) || t.has_u_in(&["ktvA", "lyap", "tosu~n", "kasu~n"])))
+        }
+        None => false,
+    }
+}
@@ -68,6 +68,10 @@ fn try_do_dirgha(p: &mut Prakriya, i_anga: usize) -> Option<()> {
         // hUta, jIna, ...
         let sub = al::to_dirgha(anga.antya()?)?;
         p.run_at("6.4.2", i_anga, |t| t.set_antya(&sub.to_string()));
+    } else if anga.has_u_in(&["yu", "plu\\N"]) && n.has_u("lyap") && p.is_chandasi() {
+        // viyUya, viplUya
+        let sub = al::to_dirgha(anga.antya()?)?;
+        p.run_at("6.4.58", i_anga, |t| t.set_antya(&sub.to_string()));
     } else if anga.has_u("kramu~") && n.has_u("ktvA") {
         // krantvA, krAntvA
         p.optional_run_at("6.4.18", i_anga, |t| t.set_upadha("A"));
@@ -143,6 +147,8 @@ fn try_cchvoh(p: &mut Prakriya) -> Option<()> {
 /// Applies rules that replace an initial "J" in a pratyaya with the appropriate sounds.
 ///
 /// (7.1.3 - 7.1.7)
+///
+/// Skipped: 7.1.8 ("bahulaM Candasi")
 pub fn maybe_do_jha_adesha(p: &mut Prakriya) -> Option<()> {
     let i = p.terms().len() - 1;
     let tin = p.get_if(i, |t| t.has_adi('J') && t.is_pratyaya())?;
@@ -228,6 +234,7 @@ fn try_halah_shnah_shanac(p: &mut Prakriya, i_anga: usize) -> Option<()> {
     Some(())
 }
 
+/// Skipped: 7.1.39 - 7.1.45 (Vedic replacements of sup and tin), 7.1.48 - 7.1.49
 pub fn try_pratyaya_adesha_at_index(p: &mut Prakriya, i_anga: usize) -> Option<()> {
     let anga = p.get(i_anga)?;
     let i_n = p.find_next_where(i_anga, |t| !t.is_empty())?;
@@ -253,6 +260,9 @@ pub fn try_pratyaya_adesha_at_index(p: &mut Prakriya, i_anga: usize) -> Option<(
         // -tAt substitution needs to occur early because it conditions samprasarana.
         // N is to block pit-guNa, not for replacement of the last letter.
         op::optional_adesha("7.1.35", p, i_n, "tAta~N");
+    } else if n.is_tin() && n.has_text("mas") && p.is_chandasi() {
+        // pacAmasi, smasi
+        p.optional_run_at("7.1.46", i_n, |t| t.text.push('i'));
     }
 
     let anga = p.get(i_anga)?;
@@ -262,7 +272,20 @@ pub fn try_pratyaya_adesha_at_index(p: &mut Prakriya, i_anga: usize) -> Option<(
         if anga.has_u("vida~") && anga.has_gana(Adadi) && n.has_u("Satf~") {
             op::optional_adesha("7.1.36", p, i_n, "vasu~");
         } else if n.has_u("ktvA") && p.terms().first()?.is_pratipadika() {
-            op::adesha("7.1.37", p, i_n, "lyap");
+            // paridhApayitvA
+            if !(p.is_chandasi() && p.optional_run("7.1.38", |_| {})) {
+                op::adesha("7.1.37", p, i_n, "lyap");
+            }
+        }
+
+        // Check again in case 7.1.37 applied.
+        if p.has(i_n, |t| t.has_u("ktvA")) && p.is_chandasi() {
+            // dattvAya
+            //
+            // yak is kit, so it follows ktvA by 1.1.46.
+            if p.optional_run("7.1.47", |p| op::insert_agama_after(p, i_n, "yak")) {
+                it_samjna::run(p, i_n + 1).ok()?;
+            }
         }
     }
 
@@ -343,6 +366,7 @@ fn try_shiti(p: &mut Prakriya) -> Option<()> {
     Some(())
 }
 
+/// Skipped: 7.3.81 ("mInAter nigame")
 fn try_pvadinam_hrasva(p: &mut Prakriya) -> Option<()> {
     let i = p.find_last(T::Dhatu)?;
     let i_n = p.find_next_where(i, |t| !t.is_empty())?;
@@ -548,6 +572,8 @@ fn try_sarvadhatuke(p: &mut Prakriya) -> Option<()> {
 }
 
 /// (7.4.21 - 7.4.31)
+///
+/// Skipped: 7.4.35 ("na cCandasy aputrasya"), 7.4.38 ("devasumnayor yajuzi kAWake")
 fn try_change_anga_before_y(p: &mut Prakriya, i: usize) -> Option<()> {
     let anga = p.get_if(i, |t| t.is_anga())?;
     let i_n = p.find_next_where(i, |t| !t.is_empty())?;
@@ -630,6 +656,8 @@ fn try_change_anga_before_y(p: &mut Prakriya, i: usize) -> Option<()> {
 }
 
 /// Runs rules that change one or more letters in the anga to a 't'.
+///
+/// Skipped: 7.4.45 ("suDitavasuDita...")
 fn try_anga_changes_to_t(p: &mut Prakriya, i_anga: usize) -> Option<()> {
     let anga = p.get(i_anga)?;
 
@@ -655,7 +683,12 @@ fn try_anga_changes_to_t(p: &mut Prakriya, i_anga: usize) -> Option<()> {
             p.run_at("7.4.42", i_anga, op::text("hi"));
         } else if anga.has_u("o~hA\\k") && next.has_u("ktvA") {
             // Only `o~hA\\k`. ("jahāternideśāt jihīterna bhavati। hātvā" -- KV)
-            p.run_at("7.4.43", i_anga, op::text("hi"));
+            if p.is_chandasi() {
+                // hitvA, hAtvA
+                p.optional_run_at("7.4.44", i_anga, op::text("hi"));
+            } else {
+                p.run_at("7.4.43", i_anga, op::text("hi"));
+            }
         } else if anga.has_tag(T::Ghu) && anga.has_adi('d') {
             if p.has_prev_non_empty(i_anga, |t| t.is_upasarga() && t.has_antya(&*AC)) {
                 p.run_at("7.4.47", i_anga, op::text("t"));
@@ -867,6 +900,7 @@ fn try_change_cu_to_ku(p: &mut Prakriya, i: usize) -> Option<()> {
     Some(())
 }
 
+/// Skipped: 7.1.103 ("bahulaM Candasi")
 fn try_dhatu_rt_adesha(p: &mut Prakriya, i: usize) -> Option<()> {
     let dhatu = p.get_if(i, |t| t.is_dhatu())?;
 
@@ -1070,6 +1104,8 @@ fn try_sic_vrddhi(p: &mut Prakriya) -> Option<()> {
 }
 
 /// Tries replacement of f/F with f, which blocks guna.
+///
+/// Skipped: 7.4.8 ("nityaM Candasi")
 fn try_cani_before_guna(p: &mut Prakriya) -> Option<()> {
     let i = p.find_first(T::Dhatu)?;
 
@@ -218,6 +218,8 @@ pub fn run_for_sani_or_cani(p: &mut Prakriya) -> Option<()> {
 }
 
 /// Runs abhyasa rules that apply generally.
+///
+/// Skipped: 7.4.64 ("kfzeS Candasi")
 fn try_general_rules(p: &mut Prakriya, i: usize) -> Option<()> {
     let i_dhatu = i + 1;
 
@@ -300,6 +302,8 @@ fn try_general_rules(p: &mut Prakriya, i: usize) -> Option<()> {
 /// Example: bu + BU + va -> baBUva.
 ///
 /// (7.4.70 - 7.4.74)
+///
+/// Skipped: 7.4.74 ("sasUveti nigame")
 fn try_rules_for_lit(p: &mut Prakriya, i: usize) -> Option<()> {
     let i_dhatu = i + 1;
 
@@ -358,7 +362,9 @@ fn try_rules_for_lit(p: &mut Prakriya, i: usize) -> Option<()> {
 ///
 /// Example: bu + BU + va -> baBUva.
 ///
-/// (7.4.75 - 7.4.77)
+/// (7.4.75 - 7.4.78)
+///
+/// Skipped: 7.4.78 ("bahulaM Candasi") except for gA
 fn try_rules_for_slu(p: &mut Prakriya, i: usize) -> Option<()> {
     p.find_last(T::Slu)?;
 
@@ -185,6 +185,8 @@ fn run_for_kniti_ardhadhatuke_after_guna(p: &mut Prakriya, i: usize) -> Option<(
                     // pradAya
                     p.step("6.4.69");
                 }
+            } else if dhatu.has_u("o~hA\\k") && n.has_u("ktvA") {
+                // hAtvA (if 7.4.44 was declined) -- KV on 7.4.44.
             } else {
                 // deya
                 p.run_at("6.4.66", i, op::antya("I"));
@@ -234,6 +236,8 @@ fn run_for_kniti_ardhadhatuke_after_dvitva(p: &mut Prakriya, i: usize) -> Option
 /// Runs rules conditioned on a following `kit` or `Nit` suffix.
 ///
 /// (6.4.98 - 6.4.126)
+///
+/// Skipped: 6.4.99 ("tanipatyoS Candasi")
 fn try_run_kniti_for_dhatu(p: &mut Prakriya, i: usize) -> Option<()> {
     let anga = p.get(i)?;
     let j = p.find_next_where(i, |t| !t.is_empty())?;
@@ -262,6 +266,8 @@ fn try_run_kniti_for_dhatu(p: &mut Prakriya, i: usize) -> Option<()> {
 /// Runs rules conditioned on a following `kit` or `Nit` suffix.
 ///
 /// (6.4.98 - 6.4.126)
+///
+/// Skipped: 6.4.102 for pF, kf, vf, and for Sru without a vikarana
 fn try_run_kniti(p: &mut Prakriya, i: usize) -> Option<()> {
     let anga = p.get(i)?;
     let j = p.find_next_where(i, |t| !t.is_empty())?;
@@ -280,7 +286,10 @@ fn try_run_kniti(p: &mut Prakriya, i: usize) -> Option<()> {
         let n = p.pratyaya(j)?;
         let n_is_mv = n.has_adi('m') || n.has_adi('v');
 
-        if !is_samyogapurva(p, i) && next_is_hi {
+        if dhatu.has_u("Sru\\") && next_is_hi && p.is_chandasi() {
+            // SfRuDi
+            p.run_at("6.4.102", n.start(), op::text("Di"));
+        } else if !is_samyogapurva(p, i) && next_is_hi {
             // kuruhi -> kuru
             p.run_at("6.4.106", n.start(), op::luk);
         } else if dhatu.has_text_in(&["kar", "kur"]) {
@@ -489,8 +498,10 @@ fn try_ardhadhatuke(p: &mut Prakriya, i: usize) -> Option<()> {
 
     if anga.has_text("Brasj") {
         p.optional_run_at("6.4.47", i, op::text("Barj"));
-    } else if anga.ends_with("ya") && is_halah(p, i) && !anga.has_u("kyac") {
+    } else if anga.ends_with("ya") && is_halah(p, i) && !anga.has_u_in(&["kyac", "kAmyac"]) {
         // TODO: why block kyac? SK mentions the "sannipAta-pariBAzA" in 2658
+        //
+        // Block kAmyac so that we keep its "k" (putrakAmyayati, putrakAmyitA).
         p.run_at("6.4.49", i, |t| t.set_adi(""));
     }
 
@@ -678,6 +689,11 @@ fn try_antya_nalopa(p: &mut Prakriya, i: usize) -> Option<()> {
     Some(())
 }
 
+/// Adds aw-Agama or Aw-Agama before a dhatu followed by luN, laN, or lfN.
+///
+/// (6.4.71 - 6.4.72)
+///
+/// Skipped: 6.4.73 - 6.4.75 (Vedic uses of aw and Aw)
 pub fn try_add_a_agama(p: &mut Prakriya) -> Option<()> {
     let _i = p.find_last(T::Dhatu)?;
 
@@ -815,7 +831,9 @@ fn run_for_final_i_or_u(p: &mut Prakriya, i_anga: usize) -> Option<()> {
         // `Dhatu` is understood here even if not stated in the rule.
         // ("dhātoḥ iti vartate" -- Kashika)
         if anga.has_text("suDI") {
-            p.step("6.4.85");
+            if !(p.is_chandasi() && p.optional_run_at("6.4.86", i_anga, op::antya("y"))) {
+                p.step("6.4.85");
+            }
         } else {
             p.run_at("6.4.82", i_anga, op::antya("y"));
         }
@@ -826,7 +844,10 @@ fn run_for_final_i_or_u(p: &mut Prakriya, i_anga: usize) -> Option<()> {
         && is_asamyogapurva
     {
         if anga.has_text("BU") {
-            p.step("6.4.85");
+            // viBvam, viBuvam
+            if !(p.is_chandasi() && p.optional_run_at("6.4.86", i_anga, op::antya("v"))) {
+                p.step("6.4.85");
+            }
         } else {
             p.run_at("6.4.83", i_anga, op::antya("v"));
         }
@@ -974,6 +995,8 @@ fn try_kr_rule(p: &mut Prakriya, i: usize) -> Option<()> {
 ///
 /// A prakriya could have multiple "bha" terms if, for example, we have a pratipadika followed by a
 /// taddhita-pratyaya followed by a strI-pratyaya.
+///
+/// Skipped: 6.4.162 ("viBAzarjoS Candasi"), 6.4.175 ("ftvyavAstvya...")
 pub fn try_bhasya_for_index(p: &mut Prakriya, i: usize) -> Option<()> {
     const PRIYA_ADI: &[&str] = &[
         "priya",
@@ -1151,8 +1174,13 @@ pub fn try_bhasya_for_index(p: &mut Prakriya, i: usize) -> Option<()> {
                 && (bha.ends_with("man") || bha.ends_with("van"))
                 && HAL.contains(bha.get_at(n - 4)?)
             {
+                let is_mantra_atman = bha.has_text("Atman") && next.has_u("wA") && p.is_chandasi();
                 p.step("6.4.137");
                 block_lopa = true;
+                if is_mantra_atman {
+                    // tmanA
+                    p.run_at("6.4.141", i, op::adi(""));
+                }
             } else if next.has_u_in(&["Ni", "SI"]) {
                 block_lopa = p.optional_run("6.4.136", |_| {});
             }
@@ -58,8 +58,13 @@ fn try_dirgha_adesha_after_num_agama(p: &mut Prakriya) -> Option<()> {
 
     if sup.has_text("Am") && has_nuw_agama {
         if anga.has_text_in(&["tisf", "catasf"]) {
-            // No change.
-            p.step("6.4.4")
+            // tisfRAm, tisFRAm
+            let sub = al::to_dirgha(anga.antya()?)?;
+            if !(p.is_chandasi() && p.optional_run_at("6.4.5", i_anga, op::antya(&sub.to_string())))
+            {
+                // No change.
+                p.step("6.4.4")
+            }
         } else if anga.has_text("nf") {
             // nfRAm, nFRAm
             let sub = al::to_dirgha(anga.antya()?)?;
@@ -88,7 +93,12 @@ fn try_dirgha_adesha_after_num_agama(p: &mut Prakriya) -> Option<()> {
             } else if !sup.is_lupta() {
                 // PalAni
                 let sub = al::to_dirgha(anga.upadha()?)?;
-                p.run_at("6.4.8", i_anga, op::upadha(&sub.to_string()));
+                if anga.ends_with("zan") && p.is_chandasi() {
+                    // ukzaRam, ukzARam
+                    p.optional_run_at("6.4.9", i_anga, op::upadha(&sub.to_string()));
+                } else {
+                    p.run_at("6.4.8", i_anga, op::upadha(&sub.to_string()));
+                }
             }
         } else if (anga.ends_with("ns") && anga.len() >= 3)
             || p.custom_view(0, i_anga)?.has_text("mahant")
@@ -197,8 +207,15 @@ fn try_sup_adesha(p: &mut Prakriya, i_anga: usize, i_sup: usize) -> Option<()> {
                 // TODO: a-koH
                 p.step("7.1.11");
             } else {
-                // narEH
-                p.run_at("7.1.9", i_sup, op::text("Es"));
+                let mut blocked = false;
+                if p.is_chandasi() {
+                    // devebhiH
+                    blocked = p.optional_run("7.1.10", |_| {});
+                }
+                if !blocked {
+                    // narEH
+                    p.run_at("7.1.9", i_sup, op::text("Es"));
+                }
             }
         } else if is_sarvanama && sup.has_u_in(nasi_ni) {
             if let Some(sub) = yatha(&sup_u, nasi_ni, smat_smin) {
@@ -289,6 +306,9 @@ fn try_napumsaka_su_am_adesha(p: &mut Prakriya, i_anga: usize) -> Option<()> {
                 if anga.has_text("ekatara") {
                     // ekataram
                     p.step(Varttika("7.1.26.1"));
+                } else if anga.has_text("itara") && p.is_chandasi() {
+                    // itaram
+                    p.step("7.1.26");
                 } else {
                     // anyat
                     op::adesha("7.1.25", p, i_sup, "adq");
@@ -307,6 +327,8 @@ fn try_napumsaka_su_am_adesha(p: &mut Prakriya, i_anga: usize) -> Option<()> {
 }
 
 /// Adds Agamas for words like trayA-R-Am, sarve-z-Am, etc.
+///
+/// Skipped: 7.1.57 ("goH pAdAnte")
 fn try_add_sup_agamas(p: &mut Prakriya, i_anga: usize) -> Option<()> {
     let i_sup = p.find_next_where(i_anga, |t| t.is_sup())?;
 
@@ -318,6 +340,9 @@ fn try_add_sup_agamas(p: &mut Prakriya, i_anga: usize) -> Option<()> {
         if anga.first().is_sarvanama() {
             // sarvezAm, ...
             op::insert_agama_at("7.1.52", p, i_sup, "su~w");
+        } else if anga.has_text_in(&["SrI", "grAmaRI"]) && p.is_chandasi() {
+            // SrIRAm, grAmaRInAm
+            op::insert_agama_at("7.1.56", p, i_sup, "nu~w");
         } else if anga.is_hrasva() || anga.has_tag(T::Nadi) || anga.last().is_aap_pratyaya() {
             // vfkzARAm, ...
             op::insert_agama_at("7.1.54", p, i_sup, "nu~w");
@@ -330,6 +355,26 @@ fn try_add_sup_agamas(p: &mut Prakriya, i_anga: usize) -> Option<()> {
     Some(())
 }
 
+/// Adds asuk-Agama to jas in chandas.
+///
+/// Must run after jas is replaced in 7.1.17 - 7.1.22.
+fn try_add_asuk_agama(p: &mut Prakriya, i_anga: usize, i_sup: usize) -> Option<()> {
+    let anga = p.nyapu_pratipadika(i_anga)?;
+    let sup = p.get(i_sup)?;
+
+    // TODO: also allow sarvanamas (ye pUrvAsaH), which currently conflict with 7.1.17.
+    if sup.has_u("jas") && p.is_chandasi() && anga.has_antya('a') && !anga.first().is_sarvanama() {
+        // devAsaH, brAhmaRAsaH
+        //
+        // asuk is kit, so it follows jas by 1.1.46.
+        if p.optional_run("7.1.50", |p| op::insert_agama_after(p, i_sup, "asu~k")) {
+            it_samjna::run(p, i_sup + 1).ok()?;
+        }
+    }
+
+    Some(())
+}
+
 fn try_add_num_agama_to_anga(p: &mut Prakriya, i_anga: usize) -> Option<()> {
     let anga = p.get(i_anga)?;
     let sup = p.get(i_anga + 1)?;
@@ -397,6 +442,7 @@ fn try_add_num_agama_to_anga(p: &mut Prakriya, i_anga: usize) -> Option<()> {
     Some(())
 }
 
+/// Skipped: 7.1.83 ("dfksvavassvatavasAM Candasi")
 fn try_misc_rules(p: &mut Prakriya, i_anga: usize, i_sup: usize) -> Option<()> {
     let anga = p.get(i_anga)?;
     let sup = p.get(i_sup)?;
@@ -780,6 +826,8 @@ pub fn run_before_stritva(p: &mut Prakriya) -> Option<()> {
 }
 
 /// Applies various rules before the "bhasya" section in 6.4.
+///
+/// Skipped: 7.1.76 ("Candasy api dfSyate"), 7.1.77 ("I ca dvivacane")
 fn run_before_bhasya(p: &mut Prakriya) -> Option<()> {
     // Process *all* sup-pratyayas, including those that are part of aluk-samAsas.
     for i_anga in 0..p.terms().len() {
@@ -796,6 +844,8 @@ fn run_before_bhasya(p: &mut Prakriya) -> Option<()> {
     let i_anga = p.find_last_where(|t| t.is_pratipadika_or_nyapu())?;
     let i_next = i_anga + 1;
 
+    try_add_asuk_agama(p, i_anga, i_next);
+
     let anga = p.get(i_anga)?;
     let sup = p.get(i_next)?;
     if anga.has_text_in(&["asTi", "daDi", "sakTi", "akzi"])
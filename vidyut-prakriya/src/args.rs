@@ -8,6 +8,12 @@ this module.
 
 For extra flexibility, all of the pratyaya enums here provides `as_str` and `from_str` methods. For
 details on which strings are valid arguments in `from_str`, please read the source code directly.
+
+All of the types here also implement `serde`'s `Serialize` and `Deserialize`, so derivation requests
+can be stored as JSON and other formats. Nested pratipadikas (such as a krdanta within a samasa) are
+serialized in full. When deserializing, `Vec` and `bool` fields default to empty and `false`, and
+`Option` fields default to `None`. Unknown fields are rejected, and types that have a builder are
+deserialized through that builder so that they are validated in the same way.
 */
 mod macros;
 
@@ -26,13 +32,14 @@ pub use krt::*;
 pub use pada::*;
 pub use pratipadika::*;
 pub use samasa::*;
+use serde::{Deserialize, Serialize};
 pub use sup::*;
 pub use taddhita::*;
 pub use tin::*;
 pub use unadi::Unadi;
 
 /// Models a semantic condition that applies to the prakriya.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Artha {
     /// A semantic condition for a krdanta derivation.
     Krt(KrtArtha),
@@ -1,6 +1,7 @@
 use crate::args::Pratipadika;
 use crate::core::errors::Error;
 use crate::enum_boilerplate;
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 
 /// Defines a *gaṇa*.
@@ -8,7 +9,7 @@ use wasm_bindgen::prelude::wasm_bindgen;
 /// The dhatus in the Dhatupatha are organized in ten large *gaṇa*s or classes. These gaṇas
 /// add various properties to the dhatu, most notably the specific *vikaraṇa* (stem suffix) we use
 /// before sarvadhatuka suffixes.
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum Gana {
     /// The first gaṇa, whose first dhatu is `BU`.
@@ -67,7 +68,7 @@ enum_boilerplate!(Gana, {
 /// is no canonical version of the Dhatupatha, and we cannot expect that a dhatu's index is
 /// consistent across all of these versions. So we thought it better to avoid hard-coding indices
 /// or requiring callers to follow our specific conventions.)
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Antargana {
     /// Antargana of *bhU* gana. A dhatu in this antargana uses a shortened vowel when followed by
     /// Ric-pratyaya.
@@ -105,7 +106,7 @@ enum_boilerplate!(Antargana, {
 ///
 /// For details on what these pratyayas mean and what kinds of words they produce, see the comments
 /// below.
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[allow(non_camel_case_types)]
 #[wasm_bindgen]
 pub enum Sanadi {
@@ -148,13 +149,23 @@ pub enum Sanadi {
     ///
     /// Examples: buBUzati, ninIzati.
     san,
+
+    /// `kyaz`, which creates nAma-dhAtus from the lohitAdi words per 3.1.13.
+    ///
+    /// Examples: `lohitAyati`, `lohitAyate`
+    kyaz,
+
+    /// `RiN`, which creates nAma-dhAtus from pucCa, BARqa, and cIvara per 3.1.20.
+    ///
+    /// Examples: `utpucCayate`
+    RiN,
 }
 
 impl Sanadi {
     /// Returns whether this pratyaya can be added only after subantas.
     pub fn is_namadhatu(&self) -> bool {
         use Sanadi::*;
-        matches!(self, kAmyac | kyaN | kyac)
+        matches!(self, kAmyac | kyaN | kyac | kyaz | RiN)
     }
 }
 
@@ -166,6 +177,8 @@ enum_boilerplate!(Sanadi, {
     yaN => "yaN",
     yaNluk => "yaNluk",
     san => "san",
+    kyaz => "kyaz",
+    RiN => "RiN",
 });
 
 /// Models the verb root to use in some derivation.
@@ -273,7 +286,7 @@ enum_boilerplate!(Sanadi, {
 /// ````
 ///
 /// let bhu = Dhatu::mula("BU", Gana::Bhvadi);
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Dhatu {
     /// Indicates a muladhAtu from the Dhatupatha.
     Mula(Muladhatu),
@@ -282,12 +295,15 @@ pub enum Dhatu {
 }
 
 /// A dhatu from the Dhatupatha.
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Muladhatu {
     upadesha: String,
     gana: Gana,
     antargana: Option<Antargana>,
+    #[serde(default)]
     sanadi: Vec<Sanadi>,
+    #[serde(default)]
     prefixes: Vec<String>,
 }
 
@@ -342,11 +358,16 @@ impl Muladhatu {
 }
 
 /// A dhatu created from a subanta.
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+///
+/// When serialized, `other_sanadi` is called `sanadi` to match `Dhatu::sanadi`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Namadhatu {
     pratipadika: Pratipadika,
     nama_sanadi: Option<Sanadi>,
+    #[serde(default, rename = "sanadi")]
     other_sanadi: Vec<Sanadi>,
+    #[serde(default)]
     pub(crate) prefixes: Vec<String>,
 }
 
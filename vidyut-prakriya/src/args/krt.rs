@@ -4,6 +4,7 @@ use crate::args::Lakara;
 use crate::args::Subanta;
 use crate::core::errors::*;
 use crate::enum_boilerplate;
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 
 /// The complete list of ordinary krt-pratyayas.
@@ -12,7 +13,7 @@ use wasm_bindgen::prelude::wasm_bindgen;
 /// case explicitly here so that we can name pratyayas more concisely with SLP1. Doing so helps us
 /// distinguish between pratyayas like `naN` and `nan`.
 #[allow(dead_code, non_camel_case_types)]
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum BaseKrt {
     /// -a
@@ -219,6 +220,39 @@ pub enum BaseKrt {
     zwran,
     /// -aka
     zvun,
+
+    // Vedic infinitives (3.4.9). We add these at the end so that the discriminants of the
+    // variants above stay stable.
+    /// -se (vakze). Vedic infinitive.
+    se,
+    /// -se. Vedic infinitive.
+    sen,
+    /// -ase (jIvase). Vedic infinitive.
+    ase,
+    /// -ase. Vedic infinitive.
+    asen,
+    /// -se. Vedic infinitive.
+    kse,
+    /// -ase. Vedic infinitive.
+    kasen,
+    /// -aDyE (caraDyE). Vedic infinitive.
+    aDyE,
+    /// -aDyE. Vedic infinitive.
+    aDyEn,
+    /// -aDyE. Vedic infinitive.
+    kaDyE,
+    /// -aDyE. Vedic infinitive.
+    kaDyEn,
+    /// -aDyE (pibaDyE). Vedic infinitive.
+    SaDyE,
+    /// -aDyE. Vedic infinitive.
+    SaDyEn,
+    /// -tavE (pAtavE). Vedic infinitive.
+    tavE,
+    /// -tave. Vedic infinitive.
+    taveN,
+    /// -tave. Vedic infinitive.
+    taven,
 }
 
 enum_boilerplate!(BaseKrt, {
@@ -324,10 +358,25 @@ enum_boilerplate!(BaseKrt, {
     zAkan => "zAkan",
     zwran => "zwran",
     zvun => "zvu~n",
+    se => "se",
+    sen => "sen",
+    ase => "ase",
+    asen => "asen",
+    kse => "kse",
+    kasen => "kasen",
+    aDyE => "aDyE",
+    aDyEn => "aDyEn",
+    kaDyE => "kaDyE",
+    kaDyEn => "kaDyEn",
+    SaDyE => "SaDyE",
+    SaDyEn => "SaDyEn",
+    tavE => "tavE",
+    taveN => "taveN",
+    taven => "taven",
 });
 
 /// Models a krt-pratyaya.
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Krt {
     /// An ordinary krt-pratyaya as declared in the Ashtadhyayi.
     Base(BaseKrt),
@@ -351,7 +400,7 @@ impl From<Unadi> for Krt {
 ///
 /// krts are often available only in specific senses. A given krt might be allowed in one sense
 /// but blocked in another. To model and test this behavior, we use the enum below.
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum KrtArtha {
     /// Agent. (3.4.67)
     Karta,
@@ -393,7 +442,8 @@ impl Krt {
 }
 
 /// The information required to derive a krdanta in the grammar.
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "KrdantaFields")]
 pub struct Krdanta {
     /// The dhatu to add the krt-pratyaya to.
     dhatu: Dhatu,
@@ -481,6 +531,41 @@ impl Krdanta {
     }
 }
 
+/// The serialized form of `Krdanta`. We deserialize through `KrdantaBuilder` so that serialized
+/// requests are validated in the same way as requests from the builder.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KrdantaFields {
+    dhatu: Dhatu,
+    krt: Krt,
+    artha: Option<KrtArtha>,
+    lakara: Option<Lakara>,
+    upapada: Option<Subanta>,
+    require: Option<String>,
+}
+
+impl TryFrom<KrdantaFields> for Krdanta {
+    type Error = Error;
+
+    fn try_from(x: KrdantaFields) -> Result<Self> {
+        let mut b = Krdanta::builder();
+        b.dhatu(x.dhatu).krt(x.krt);
+        if let Some(artha) = x.artha {
+            b.artha(artha);
+        }
+        if let Some(lakara) = x.lakara {
+            b.lakara(lakara);
+        }
+        if let Some(upapada) = x.upapada {
+            b.upapada(upapada);
+        }
+        if let Some(require) = x.require {
+            b.require(require);
+        }
+        b.build()
+    }
+}
+
 /// Convenience struct for building a `KrdantaArgs` object.
 #[derive(Clone, Default, Eq, PartialEq)]
 pub struct KrdantaBuilder {
@@ -1,7 +1,8 @@
 use crate::args::{Subanta, Tinanta};
+use serde::{Deserialize, Serialize};
 
 /// Models a Sanskrit pada.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum Pada {
     /// A nominal word or an indeclinable.
     Subanta(Subanta),
@@ -1,4 +1,5 @@
 use crate::args::{Krdanta, Samasa, Taddhitanta};
+use serde::{Deserialize, Serialize};
 
 /// A nominal stem.
 ///
@@ -9,7 +10,7 @@ use crate::args::{Krdanta, Samasa, Taddhitanta};
 ///
 /// A pratipadika is the base to which we add sup-pratyayas. Through this process, we create
 /// subantas (nominals), which are complete words.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum Pratipadika {
     /// A simple string that receives the pratipadika-samjna by rule 1.2.45.
     Basic(BasicPratipadika),
@@ -22,10 +23,13 @@ pub enum Pratipadika {
 }
 
 /// Models a basic pratipadika.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct BasicPratipadika {
     pub(crate) text: String,
+    #[serde(default)]
     pub(crate) is_avyaya: bool,
+    #[serde(default)]
     pub(crate) is_nyap: bool,
 }
 
@@ -1,8 +1,9 @@
 use crate::args::Subanta;
 use crate::core::errors::Error;
+use serde::{Deserialize, Serialize};
 
 /// A samasa type.
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum SamasaType {
     /// 2.1.5
     Avyayibhava,
@@ -23,7 +24,8 @@ pub enum SamasaType {
 }
 
 /// The information required to derive a samasa in the grammar.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "SamasaFields")]
 pub struct Samasa {
     /// The items to combine in the samasa.
     padas: Vec<Subanta>,
@@ -61,6 +63,29 @@ impl Samasa {
     }
 }
 
+/// The serialized form of `Samasa`. We deserialize through `SamasaBuilder` so that serialized
+/// requests are validated in the same way as requests from the builder.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SamasaFields {
+    padas: Vec<Subanta>,
+    samasa_type: SamasaType,
+    #[serde(default)]
+    stri: bool,
+}
+
+impl TryFrom<SamasaFields> for Samasa {
+    type Error = Error;
+
+    fn try_from(x: SamasaFields) -> Result<Self, Error> {
+        let samasa = Samasa::builder()
+            .padas(x.padas)
+            .samasa_type(x.samasa_type)
+            .build()?;
+        Ok(samasa.with_stri(x.stri))
+    }
+}
+
 /// Convenience struct for building a `SamasaARgs` struct.
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SamasaBuilder {
@@ -89,7 +114,7 @@ impl SamasaBuilder {
             padas: if !self.padas.is_empty() {
                 self.padas.clone()
             } else {
-                return Err(Error::missing_required_field("items"));
+                return Err(Error::missing_required_field("padas"));
             },
             samasa_type: match self.samasa_type {
                 Some(x) => x,
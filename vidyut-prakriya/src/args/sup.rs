@@ -3,10 +3,11 @@ use crate::args::Pratipadika;
 use crate::core::errors::Error;
 use crate::core::Tag;
 use crate::enum_boilerplate;
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 
 /// The gender of some subanta.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum Linga {
     /// The masculine.
@@ -34,7 +35,7 @@ impl Linga {
 }
 
 /// The case ending of some subanta.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum Vibhakti {
     /// The first vibhakti . Sometimes called the *nominative case*.
@@ -87,7 +88,8 @@ impl Vibhakti {
 }
 
 /// The information required to derive a subanta in the grammar.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "SubantaFields")]
 pub struct Subanta {
     pratipadika: Pratipadika,
     linga: Linga,
@@ -157,6 +159,48 @@ impl Subanta {
     }
 }
 
+/// The serialized form of `Subanta`. We deserialize through `SubantaBuilder` so that serialized
+/// requests are validated in the same way as requests from the builder.
+///
+/// If `is_avyaya` is set, `linga`, `vibhakti`, and `vacana` are optional, as in `Subanta::avyaya`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SubantaFields {
+    pratipadika: Pratipadika,
+    linga: Option<Linga>,
+    vibhakti: Option<Vibhakti>,
+    vacana: Option<Vacana>,
+    #[serde(default)]
+    is_avyaya: bool,
+}
+
+impl TryFrom<SubantaFields> for Subanta {
+    type Error = Error;
+
+    fn try_from(x: SubantaFields) -> Result<Self, Error> {
+        if x.is_avyaya {
+            let mut ret = Subanta::avyaya(x.pratipadika);
+            ret.linga = x.linga.unwrap_or(ret.linga);
+            ret.vibhakti = x.vibhakti.unwrap_or(ret.vibhakti);
+            ret.vacana = x.vacana.unwrap_or(ret.vacana);
+            return Ok(ret);
+        }
+
+        let mut b = Subanta::builder();
+        b.pratipadika(x.pratipadika);
+        if let Some(linga) = x.linga {
+            b.linga(linga);
+        }
+        if let Some(vibhakti) = x.vibhakti {
+            b.vibhakti(vibhakti);
+        }
+        if let Some(vacana) = x.vacana {
+            b.vacana(vacana);
+        }
+        b.build()
+    }
+}
+
 /// Convenience struct for building a `SubantaArgs` struct.
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct SubantaBuilder {
@@ -1,6 +1,7 @@
 use crate::args::Pratipadika;
 use crate::core::errors::*;
 use crate::enum_boilerplate;
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 
 /// The complete list of taddhita-pratyayas.
@@ -9,7 +10,7 @@ use wasm_bindgen::prelude::wasm_bindgen;
 /// case explicitly here so that we can name pratyayas more concisely with SLP1. Doing so helps us
 /// distinguish between pratyayas like `naN` and `nan`.
 #[allow(dead_code, non_camel_case_types)]
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum Taddhita {
     /// a
@@ -537,7 +538,7 @@ enum_boilerplate!(Taddhita, {
 ///
 /// Generally, taddhitas are available only in specific senses. A given taddhita might be allowed
 /// in one sense but blocked in another. To model and test this behavior, we use the enum below.
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum TaddhitaArtha {
     /// Descendant. (4.1.92)
     TasyaApatyam,
@@ -820,6 +821,148 @@ pub enum TaddhitaArtha {
     AbhutaTadbhava,
 }
 
+enum_boilerplate!(TaddhitaArtha, {
+    TasyaApatyam => "TasyaApatyam",
+    Gotra => "Gotra",
+    Jatau => "Jatau",
+    Janapada => "Janapada",
+    TenaRaktam => "TenaRaktam",
+    SamskrtamBhaksha => "SamskrtamBhaksha",
+    SaAsyaDevata => "SaAsyaDevata",
+    TasyaSamuha => "TasyaSamuha",
+    TasyaVishayoDeshe => "TasyaVishayoDeshe",
+    TadAdhiteTadVeda => "TadAdhiteTadVeda",
+    Caturarthika => "Caturarthika",
+    Sheshe => "Sheshe",
+    TatraJata => "TatraJata",
+    TatraKrtaLabdhaKritaKushala => "TatraKrtaLabdhaKritaKushala",
+    TatraPrayabhava => "TatraPrayabhava",
+    TatraSambhute => "TatraSambhute",
+    TatraBhava => "TatraBhava",
+    TataAgata => "TataAgata",
+    AsyaNivasa => "AsyaNivasa",
+    Bhakti => "Bhakti",
+    TenaProktam => "TenaProktam",
+    TenaKrte => "TenaKrte",
+    TasyaIdam => "TasyaIdam",
+    TasyaVikara => "TasyaVikara",
+    TenaDivyatiJayatiJitam => "TenaDivyatiJayatiJitam",
+    TenaSamskrtam => "TenaSamskrtam",
+    TenaTarati => "TenaTarati",
+    TenaCarati => "TenaCarati",
+    TenaJivati => "TenaJivati",
+    TenaHarati => "TenaHarati",
+    TenaNirvrtte => "TenaNirvrtte",
+    TenaSamsrshte => "TenaSamsrshte",
+    TenaUpasikte => "TenaUpasikte",
+    Vartate => "Vartate",
+    PrayacchatiGarhyam => "PrayacchatiGarhyam",
+    Unchati => "Unchati",
+    TadRakshati => "TadRakshati",
+    Karoti => "Karoti",
+    Hanti => "Hanti",
+    Grhnati => "Grhnati",
+    Carati => "Carati",
+    Eti => "Eti",
+    Samavaiti => "Samavaiti",
+    Pashyati => "Pashyati",
+    TasyaDharmyam => "TasyaDharmyam",
+    TadAsyaPanyam => "TadAsyaPanyam",
+    Praharanam => "Praharanam",
+    Avakraya => "Avakraya",
+    Shilpam => "Shilpam",
+    Mati => "Mati",
+    Shilam => "Shilam",
+    KarmaAdhyayaneVrttam => "KarmaAdhyayaneVrttam",
+    HitamBhaksha => "HitamBhaksha",
+    TadAsmaiDiyateNiyuktam => "TadAsmaiDiyateNiyuktam",
+    Niyuktam => "Niyuktam",
+    Vyavaharati => "Vyavaharati",
+    Vasati => "Vasati",
+    TadVahati => "TadVahati",
+    TadVidhyati => "TadVidhyati",
+    Labdha => "Labdha",
+    Gata => "Gata",
+    AsminDrshyam => "AsminDrshyam",
+    AsyaAbarhi => "AsyaAbarhi",
+    Samyukta => "Samyukta",
+    Anapeta => "Anapeta",
+    Nirmita => "Nirmita",
+    Priya => "Priya",
+    TatraSadhu => "TatraSadhu",
+    TatraVasi => "TatraVasi",
+    TasmaiHitam => "TasmaiHitam",
+    TadarthamVikrtehPrakrtau => "TadarthamVikrtehPrakrtau",
+    TadAsyaTadAsminSyat => "TadAsyaTadAsminSyat",
+    TenaKritam => "TenaKritam",
+    TasyaNimittamSamyogotpattau => "TasyaNimittamSamyogotpattau",
+    TasyaVapa => "TasyaVapa",
+    TadAsminVrddhiAyaLabhaSulkaUpada => "TadAsminVrddhiAyaLabhaSulkaUpada",
+    TadDharatiVahatiAvahati => "TadDharatiVahatiAvahati",
+    SambhavatiAharatiPacati => "SambhavatiAharatiPacati",
+    TadAsyaParimanam => "TadAsyaParimanam",
+    TadArhati => "TadArhati",
+    TadVartayati => "TadVartayati",
+    Apanna => "Apanna",
+    Gacchati => "Gacchati",
+    AbhigamanamArhati => "AbhigamanamArhati",
+    Ahrtam => "Ahrtam",
+    TenaNirvrttam => "TenaNirvrttam",
+    TamAdhisteBhrtoBhutoBhavi => "TamAdhisteBhrtoBhutoBhavi",
+    Vayasi => "Vayasi",
+    TasyaBhava => "TasyaBhava",
+    DhanyanamBhavaneKshetre => "DhanyanamBhavaneKshetre",
+    Krta => "Krta",
+    Darshana => "Darshana",
+    Vyapnoti => "Vyapnoti",
+    Prapnoti => "Prapnoti",
+    TadAnubhavati => "TadAnubhavati",
+    Gami => "Gami",
+    AlamGami => "AlamGami",
+    BhutaPurva => "BhutaPurva",
+    EkahaGama => "EkahaGama",
+    TasyaPakamula => "TasyaPakamula",
+    TasyaMula => "TasyaMula",
+    TenaVitta => "TenaVitta",
+    TadAsyaSamjatam => "TadAsyaSamjatam",
+    TadAsyaPramanam => "TadAsyaPramanam",
+    Parimana => "Parimana",
+    Avasana => "Avasana",
+    Nimana => "Nimana",
+    Purana => "Purana",
+    TatraKushala => "TatraKushala",
+    TatraKama => "TatraKama",
+    TatraAdyuna => "TatraAdyuna",
+    TatraParijata => "TatraParijata",
+    Hari => "Hari",
+    AciraApahrta => "AciraApahrta",
+    Karin => "Karin",
+    Anvicchati => "Anvicchati",
+    TadAsyaAstiAsmin => "TadAsyaAstiAsmin",
+    DigDeshaKala => "DigDeshaKala",
+    Ajnate => "Ajnate",
+    Kutsite => "Kutsite",
+    Anukampayam => "Anukampayam",
+    Tanutve => "Tanutve",
+    DvayorEka => "DvayorEka",
+    BahunamEka => "BahunamEka",
+    Avakshepane => "Avakshepane",
+    Alpe => "Alpe",
+    Hrasve => "Hrasve",
+    IvePratikrtau => "IvePratikrtau",
+    AyudhaJiviSangha => "AyudhaJiviSangha",
+    AnatyantaGati => "AnatyantaGati",
+    Acchadana => "Acchadana",
+    Svarthe => "Svarthe",
+    Matsye => "Matsye",
+    KriyaAbhyavrttiGanana => "KriyaAbhyavrttiGanana",
+    PrakaraVacane => "PrakaraVacane",
+    TatPrakrtaVacane => "TatPrakrtaVacane",
+    Tadarthye => "Tadarthye",
+    Prashamsa => "Prashamsa",
+    AbhutaTadbhava => "AbhutaTadbhava",
+});
+
 impl TaddhitaArtha {
     /// Returns whether `self` is either identical to `other` or falls under `other` as a subtype.
     pub fn is_type_of(&self, parent: Self) -> bool {
@@ -831,7 +974,8 @@ impl TaddhitaArtha {
 }
 
 /// The information required to derive a taddhitanta in the grammar.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "TaddhitantaFields")]
 pub struct Taddhitanta {
     pratipadika: Pratipadika,
     taddhita: Taddhita,
@@ -872,6 +1016,33 @@ impl Taddhitanta {
     }
 }
 
+/// The serialized form of `Taddhitanta`. We deserialize through `TaddhitantaBuilder` so that
+/// serialized requests are validated in the same way as requests from the builder.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TaddhitantaFields {
+    pratipadika: Pratipadika,
+    taddhita: Taddhita,
+    artha: Option<TaddhitaArtha>,
+    require: Option<String>,
+}
+
+impl TryFrom<TaddhitantaFields> for Taddhitanta {
+    type Error = Error;
+
+    fn try_from(x: TaddhitantaFields) -> Result<Self> {
+        let mut b = Taddhitanta::builder();
+        b.pratipadika(x.pratipadika).taddhita(x.taddhita);
+        if let Some(artha) = x.artha {
+            b.artha(artha);
+        }
+        if let Some(require) = x.require {
+            b.require(require);
+        }
+        b.build()
+    }
+}
+
 /// Convenience struct for building a `TaddhitantaArgs` object.
 #[derive(Clone, Default, Eq, Hash, PartialEq)]
 pub struct TaddhitantaBuilder {
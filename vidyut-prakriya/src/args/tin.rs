@@ -2,10 +2,11 @@ use crate::args::dhatu::Dhatu;
 use crate::core::errors::Error;
 use crate::core::Tag;
 use crate::enum_boilerplate;
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 
 /// The prayoga of some tinanta.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum Prayoga {
     /// Usage coreferent with the agent, e.g. "The horse *goes* to the village."
@@ -34,7 +35,7 @@ impl Prayoga {
 }
 
 /// The person of some tinanta.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum Purusha {
     /// The third person.
@@ -62,7 +63,7 @@ impl Purusha {
 }
 
 /// The number of some tinanta or subanta.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum Vacana {
     /// The singular.
@@ -90,7 +91,7 @@ impl Vacana {
 }
 
 /// The tense/mood of some tinanta.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum Lakara {
     /// Describes action in the present tense. Ssometimes called the *present indicative*.
@@ -101,7 +102,7 @@ pub enum Lakara {
     Lut,
     /// Describes general future action. Sometimes called the *simple future*.
     Lrt,
-    /// The Vedic subjunctive. `vidyut-prakriya` currently has poor support for this lakara.
+    /// The Vedic subjunctive. Paradigms include this lakara only if `is_chandasi` is set.
     Let,
     /// Describes commands. Sometimes called the *imperative*.
     Lot,
@@ -155,7 +156,7 @@ impl Lakara {
 }
 
 /// The pada of some tinanta or krdanta.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum DhatuPada {
     /// Parasmaipada.
@@ -189,7 +190,8 @@ impl DhatuPada {
 ///
 /// Since we want to keep these args manageable and don't want to repeatedly break our main API, we
 /// decided to wrap args in this struct and expose its values through accessors.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "TinantaFields")]
 pub struct Tinanta {
     dhatu: Dhatu,
     prayoga: Prayoga,
@@ -267,6 +269,36 @@ impl Tinanta {
     }
 }
 
+/// The serialized form of `Tinanta`. We deserialize through `TinantaArgsBuilder` so that serialized
+/// requests are validated in the same way as requests from the builder.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TinantaFields {
+    dhatu: Dhatu,
+    prayoga: Prayoga,
+    lakara: Lakara,
+    purusha: Purusha,
+    vacana: Vacana,
+    pada: Option<DhatuPada>,
+}
+
+impl TryFrom<TinantaFields> for Tinanta {
+    type Error = Error;
+
+    fn try_from(x: TinantaFields) -> Result<Self, Error> {
+        let mut b = Tinanta::builder()
+            .dhatu(x.dhatu)
+            .prayoga(x.prayoga)
+            .lakara(x.lakara)
+            .purusha(x.purusha)
+            .vacana(x.vacana);
+        if let Some(pada) = x.pada {
+            b = b.pada(pada);
+        }
+        b.build()
+    }
+}
+
 /// Convenience struct for building a `TinantaArgs` object.
 ///
 ///
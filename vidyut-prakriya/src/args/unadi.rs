@@ -1,6 +1,7 @@
 use crate::core::errors::*;
 use crate::enum_boilerplate;
 
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 
 /// The complete list of unadi-pratyayas.
@@ -11,7 +12,7 @@ use wasm_bindgen::prelude::wasm_bindgen;
 ///
 /// NOTE: we generated this list programmatically. Many of these pratyayas have typos.
 #[allow(dead_code, non_camel_case_types)]
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[wasm_bindgen]
 pub enum Unadi {
     /// -a
@@ -27,8 +27,8 @@ use crate::ac_sandhi;
 use crate::angasya;
 use crate::ardhadhatuka;
 use crate::args::{
-    Artha, Dhatu, Krdanta, Lakara, Pada, Pratipadika, Prayoga, Samasa, Subanta, Taddhitanta,
-    Tinanta,
+    Artha, Dhatu, Krdanta, Lakara, Linga, Pada, Pratipadika, Prayoga, Purusha, Samasa, Sanadi,
+    Subanta, Taddhitanta, Tinanta, Vacana, Vibhakti,
 };
 use crate::atidesha;
 use crate::atmanepada;
@@ -71,10 +71,9 @@ fn prepare_dhatu(p: &mut Prakriya, dhatu: &Dhatu, is_ardhadhatuka: bool) -> Resu
         }
         Dhatu::Nama(n) => {
             dhatu_karya::try_add_prefixes(p, n.prefixes());
-            sanadi::try_create_namadhatu(p, n);
+            sanadi::try_create_namadhatu(p, n)?;
             if !p.terms().last().expect("ok").is_dhatu() {
-                println!("{:#?}", p);
-                return Err(Error::Abort(p.rule_choices().clone()));
+                return Err(Error::abort(p, AbortReason::NamadhatuNotCreated));
             }
         }
     }
@@ -86,18 +85,24 @@ fn prepare_dhatu(p: &mut Prakriya, dhatu: &Dhatu, is_ardhadhatuka: bool) -> Resu
         // Defer tripadi until we add other pratyayas.
     }
 
-    if let Dhatu::Mula(_) = dhatu {
-        for s in dhatu.sanadi() {
-            // HACK: reset padas for next sanadi.
-            p.remove_tag(Tag::Parasmaipada);
-            p.remove_tag(Tag::Atmanepada);
-
-            sanadi::try_add_optional(p, *s)?;
-            samjna::run(p);
-            atmanepada::run(p);
-            run_main_rules(p, None, false)?;
-            // Defer tripadi until we add other pratyayas.
+    // Add sanadi-pratyayas in order. For namadhatus, these follow the pratyaya that created the
+    // namadhatu (putrIyayati, ...).
+    for s in dhatu.sanadi() {
+        // TODO: support dvitva for a namadhatu + san (putrIyiyizati, ...). Until then, reject san
+        // so that we don't return forms without dvitva.
+        if matches!(dhatu, Dhatu::Nama(_)) && *s == Sanadi::san {
+            return Err(Error::abort(p, AbortReason::SanadiNotAdded(*s)));
         }
+
+        // HACK: reset padas for next sanadi.
+        p.remove_tag(Tag::Parasmaipada);
+        p.remove_tag(Tag::Atmanepada);
+
+        sanadi::try_add_optional(p, *s)?;
+        samjna::run(p);
+        atmanepada::run(p);
+        run_main_rules(p, None, false)?;
+        // Defer tripadi until we add other pratyayas.
     }
 
     p.debug("~~~~~~~~~~~~~~ completed dhatu ~~~~~~~~~~~~~~~~~~");
@@ -129,8 +134,7 @@ fn prepare_krdanta(p: &mut Prakriya, args: &Krdanta) -> Result<()> {
     }
     let added = krt::run(p, args);
     if !added {
-        println!("{:#?}", p);
-        return Err(Error::Abort(p.rule_choices().clone()));
+        return Err(Error::abort(p, AbortReason::KrtNotAdded(krt)));
     }
 
     if args.upapada().is_some() {
@@ -163,7 +167,8 @@ fn prepare_pratipadika(p: &mut Prakriya, pratipadika: &Pratipadika) -> Result<()
                 }
             }
             if !added {
-                return Err(Error::Abort(p.rule_choices().clone()));
+                let text = k.require().clone().unwrap_or_default();
+                return Err(Error::abort(p, AbortReason::RequiredTextNotDerived(text)));
             }
         }
         Pratipadika::Taddhitanta(t) if t.require().is_some() => {
@@ -181,7 +186,8 @@ fn prepare_pratipadika(p: &mut Prakriya, pratipadika: &Pratipadika) -> Result<()
                 }
             }
             if !added {
-                return Err(Error::Abort(p.rule_choices().clone()));
+                let text = t.require().clone().unwrap_or_default();
+                return Err(Error::abort(p, AbortReason::RequiredTextNotDerived(text)));
             }
         }
         Prati::Basic(basic) => pratipadika_karya::add_basic(p, basic),
@@ -212,10 +218,7 @@ fn prepare_taddhitanta(p: &mut Prakriya, args: &Taddhitanta) -> Result<()> {
 
     let added = taddhita::run(p, taddhita);
     if !added {
-        if cfg!(debug_assertions) {
-            println!("{:?}: {:#?}", args.taddhita(), p);
-        }
-        return Err(Error::Abort(p.rule_choices().clone()));
+        return Err(Error::abort(p, AbortReason::TaddhitaNotAdded(taddhita)));
     }
 
     angasya::run_before_stritva(p);
@@ -251,7 +254,7 @@ fn prepare_samasa(p: &mut Prakriya, args: &Samasa) -> Result<()> {
 
     let added = samasa::run(p, args);
     if !added {
-        return Err(Error::Abort(p.rule_choices().clone()));
+        return Err(Error::abort(p, AbortReason::SamasaNotCreated));
     }
 
     pratipadika_karya::run_napumsaka_rules(p);
@@ -412,37 +415,89 @@ pub fn derive_tinanta(mut prakriya: Prakriya, args: &Tinanta) -> Result<Prakriya
     let p = &mut prakriya;
     let prayoga = args.prayoga();
     let lakara = args.lakara();
-    let purusha = args.purusha();
-    let vacana = args.vacana();
-    p.add_tags(&[prayoga.as_tag(), purusha.as_tag(), vacana.as_tag()]);
-    p.set_lakara(lakara);
+    prepare_tinanta_dhatu(p, args.dhatu(), prayoga, lakara)?;
+    finish_tinanta(p, prayoga, lakara, args.purusha(), args.vacana())?;
 
+    Ok(prakriya)
+}
+
+/// Returns whether a tinanta with the given prayoga and lakara will use an ardhadhatuka pratyaya.
+fn is_ardhadhatuka_tinanta(prayoga: Prayoga, lakara: Lakara) -> bool {
     // Prayogas other than kartari will never be sarvadhatuka, since yak-vikarana is not
     // sarvadhatuka.
-    let is_ardhadhatuka = match prayoga {
+    match prayoga {
         Prayoga::Kartari => lakara.is_ardhadhatuka(),
         _ => true,
-    };
+    }
+}
 
-    prepare_dhatu(p, args.dhatu(), is_ardhadhatuka)?;
+/// Runs the first part of `derive_tinanta`, which prepares the dhatu.
+///
+/// This part doesn't depend on the purusha or vacana, so all nine tinantas for some lakara can
+/// share it. (See `PrakriyaStack::find_all_branched`.)
+pub fn prepare_tinanta_dhatu(
+    p: &mut Prakriya,
+    dhatu: &Dhatu,
+    prayoga: Prayoga,
+    lakara: Lakara,
+) -> Result<()> {
+    p.add_tag(prayoga.as_tag());
+    p.set_lakara(lakara);
+    prepare_dhatu(p, dhatu, is_ardhadhatuka_tinanta(prayoga, lakara))
+}
+
+/// Runs the rest of `derive_tinanta`, which adds the tin-pratyaya and completes the derivation.
+pub fn finish_tinanta(
+    p: &mut Prakriya,
+    prayoga: Prayoga,
+    lakara: Lakara,
+    purusha: Purusha,
+    vacana: Vacana,
+) -> Result<()> {
+    p.add_tags(&[purusha.as_tag(), vacana.as_tag()]);
     add_lakara_and_decide_pada(p, lakara);
     tin_pratyaya::adesha(p, purusha, vacana);
     samjna::run(p);
-    run_main_rules(p, Some(lakara), is_ardhadhatuka)?;
+    run_main_rules(p, Some(lakara), is_ardhadhatuka_tinanta(prayoga, lakara))?;
     tripadi::run(p);
 
-    Ok(prakriya)
+    Ok(())
 }
 
 /// Derives a single subanta from the given conditions.
 pub fn derive_subanta(mut prakriya: Prakriya, args: &Subanta) -> Result<Prakriya> {
     let p = &mut prakriya;
-    prepare_pratipadika(p, args.pratipadika())?;
+    prepare_subanta_pratipadika(p, args.pratipadika(), args.linga())?;
+    finish_subanta(p, args.linga(), args.vibhakti(), args.vacana())?;
+
+    Ok(prakriya)
+}
+
+/// Runs the first part of `derive_subanta`, which prepares the pratipadika.
+///
+/// This part doesn't depend on the vibhakti or vacana, so all subantas for some pratipadika and
+/// linga can share it. (See `PrakriyaStack::find_all_branched`.)
+pub fn prepare_subanta_pratipadika(
+    p: &mut Prakriya,
+    pratipadika: &Pratipadika,
+    linga: Linga,
+) -> Result<()> {
+    prepare_pratipadika(p, pratipadika)?;
 
-    p.add_tag(args.linga().as_tag());
+    p.add_tag(linga.as_tag());
     pratipadika_karya::run_napumsaka_rules(p);
 
-    sup_karya::run(p, args.linga(), args.vibhakti(), args.vacana());
+    Ok(())
+}
+
+/// Runs the rest of `derive_subanta`, which adds the sup-pratyaya and completes the derivation.
+pub fn finish_subanta(
+    p: &mut Prakriya,
+    linga: Linga,
+    vibhakti: Vibhakti,
+    vacana: Vacana,
+) -> Result<()> {
+    sup_karya::run(p, linga, vibhakti, vacana);
     samjna::run(p);
 
     samasa::run_rules_for_avyayibhava(p);
@@ -459,7 +514,7 @@ pub fn derive_subanta(mut prakriya: Prakriya, args: &Subanta) -> Result<Prakriya
     run_main_rules(p, None, false)?;
     tripadi::run(p);
 
-    Ok(prakriya)
+    Ok(())
 }
 
 /// Derives a single krdanta from the given conditions.
@@ -482,6 +537,22 @@ pub fn derive_taddhitanta(mut prakriya: Prakriya, args: &Taddhitanta) -> Result<
     Ok(prakriya)
 }
 
+/// Derives `pratipadika` without adding any sup-pratyaya.
+///
+/// Basic pratipadikas are used as-is. All other pratipadikas run through the main rules so that
+/// callers can inspect their final form.
+pub fn derive_pratipadika(mut prakriya: Prakriya, pratipadika: &Pratipadika) -> Result<Prakriya> {
+    let p = &mut prakriya;
+    prepare_pratipadika(p, pratipadika)?;
+
+    if !matches!(pratipadika, Pratipadika::Basic(_)) {
+        run_main_rules(p, None, false)?;
+        tripadi::run(p);
+    }
+
+    Ok(prakriya)
+}
+
 pub fn derive_stryanta(mut prakriya: Prakriya, pratipadika: &Pratipadika) -> Result<Prakriya> {
     let p = &mut prakriya;
     prepare_pratipadika(p, pratipadika)?;
@@ -499,7 +570,7 @@ pub fn derive_stryanta(mut prakriya: Prakriya, pratipadika: &Pratipadika) -> Res
 /// Creates a dummy sup-pratyaya.
 ///
 /// Scope: samasas
-fn make_sup_pratyaya(vibhakti: crate::args::Vibhakti) -> Term {
+fn make_sup_pratyaya(vibhakti: Vibhakti) -> Term {
     use crate::args::Vibhakti::*;
     use crate::core::Tag as T;
     let (u, vibhakti) = match vibhakti {
@@ -536,45 +607,41 @@ pub fn derive_samasa(mut prakriya: Prakriya, args: &Samasa) -> Result<Prakriya>
     Ok(prakriya)
 }
 
-pub fn derive_vakya(mut prakriya: Prakriya, padas: &[Pada]) -> Result<Prakriya> {
+/// Derives a single vakya from the given padas, each of which was created by `derive_pada`.
+pub fn derive_vakya(mut prakriya: Prakriya, padas: &[&Prakriya]) -> Result<Prakriya> {
     for pada in padas {
-        match pada {
-            Pada::Subanta(s) => {
-                let mut stack = PrakriyaStack::new(false, false, false);
-                stack.find_all(|p| derive_subanta(p, s));
-
-                if let Some(p) = stack.prakriyas().first() {
-                    prakriya.extend(p.terms());
-                }
-            }
-            Pada::Tinanta(t) => {
-                let mut stack = PrakriyaStack::new(false, false, false);
-                stack.find_all(|p| derive_tinanta(p, t));
-
-                if let Some(p) = stack.prakriyas().first() {
-                    prakriya.extend(p.terms());
-                }
-            }
-            Pada::Dummy(s) => {
-                let mut pada = Term::make_upadesha(s);
-                pada.add_tags(&[Tag::Pada]);
-                prakriya.push(pada);
-            }
-            Pada::Nipata(s) => {
-                let mut pada = Term::make_upadesha(s);
-                pada.add_tags(&[Tag::Pada, Tag::Avyaya, Tag::Nipata]);
-                if pada.has_antya('N') || pada.has_antya('Y') {
-                    pada.set_antya("");
-                }
-                prakriya.push(pada);
-            }
-        }
+        prakriya.extend(pada.terms());
+        prakriya.extend_history(pada.history());
     }
 
     let p = &mut prakriya;
+    p.debug("~~~~~~~~~~~~~~ completed padas ~~~~~~~~~~~~~~~~~~");
     samjna::try_pragrhya_rules(p);
     run_main_rules(p, None, false)?;
     tripadi::run(p);
 
     Ok(prakriya)
 }
+
+/// Derives a single pada for use in some vakya.
+pub fn derive_pada(mut prakriya: Prakriya, pada: &Pada) -> Result<Prakriya> {
+    match pada {
+        Pada::Subanta(s) => derive_subanta(prakriya, s),
+        Pada::Tinanta(t) => derive_tinanta(prakriya, t),
+        Pada::Dummy(s) => {
+            let mut pada = Term::make_upadesha(s);
+            pada.add_tags(&[Tag::Pada]);
+            prakriya.push(pada);
+            Ok(prakriya)
+        }
+        Pada::Nipata(s) => {
+            let mut pada = Term::make_upadesha(s);
+            pada.add_tags(&[Tag::Pada, Tag::Avyaya, Tag::Nipata]);
+            if pada.has_antya('N') || pada.has_antya('Y') {
+                pada.set_antya("");
+            }
+            prakriya.push(pada);
+            Ok(prakriya)
+        }
+    }
+}
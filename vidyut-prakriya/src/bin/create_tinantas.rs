@@ -6,7 +6,9 @@ use clap::Parser;
 use serde::Serialize;
 use std::error::Error;
 use std::io;
-use vidyut_prakriya::args::{Dhatu, Lakara, Muladhatu, Prayoga, Purusha, Sanadi, Tinanta, Vacana};
+use vidyut_prakriya::args::{
+    Dhatu, Lakara, Muladhatu, Pratipadika, Prayoga, Purusha, Sanadi, Vacana,
+};
 use vidyut_prakriya::{Dhatupatha, Vyakarana};
 
 #[derive(Parser)]
@@ -14,8 +16,15 @@ use vidyut_prakriya::{Dhatupatha, Vyakarana};
 struct Args {
     #[arg(long)]
     prayoga: Option<Prayoga>,
+    /// The sanadi-pratyayas to add, in order. Repeat to stack them, e.g.
+    /// `--sanadi Ric --sanadi san`.
     #[arg(long)]
-    sanadi: Option<Sanadi>,
+    sanadi: Vec<Sanadi>,
+    /// If set, create nAma-dhAtus from `NAMADHATU_PRATIPADIKAS` instead of using the dhatupatha.
+    /// We try each pratipadika with each nAma-dhAtu pratyaya and add any `--sanadi` pratyayas
+    /// after it.
+    #[arg(long)]
+    namadhatus: bool,
 }
 
 // TODO: reuse with other binaries?
@@ -46,6 +55,27 @@ const TIN_SEMANTICS: &[(Purusha, Vacana)] = &[
 
 const PRAYOGAS: &[Prayoga] = &[Prayoga::Kartari, Prayoga::Karmani];
 
+const NAMA_SANADI: &[Sanadi] = &[
+    Sanadi::kyac,
+    Sanadi::kAmyac,
+    Sanadi::kyaN,
+    Sanadi::kyaz,
+    Sanadi::RiN,
+];
+
+/// Pratipadikas that cover the rules for each nAma-dhAtu pratyaya.
+#[rustfmt::skip]
+const NAMADHATU_PRATIPADIKAS: &[&str] = &[
+    // kyac, kAmyac
+    "putra", "rAjan", "go", "nO", "kavi", "vAc", "gir", "dadhi", "maDu", "kartf",
+    // kyaN
+    "Syena", "kumuda", "BfSa", "Sigra", "ojas", "apsaras",
+    // kyaz
+    "lohita", "nIla",
+    // RiN
+    "pucCa", "BARqa", "cIvara",
+];
+
 #[derive(Debug, Serialize)]
 struct Row<'a> {
     padas: String,
@@ -66,14 +96,63 @@ fn to_mula(dhatu: &Dhatu) -> &Muladhatu {
     }
 }
 
-fn run(d: Dhatupatha, args: Args) -> Result<(), Box<dyn Error>> {
+/// Writes all tinantas for `dhatu` to `wtr`.
+fn write_tinantas(
+    wtr: &mut csv::Writer<io::Stdout>,
+    v: &Vyakarana,
+    args: &Args,
+    dhatu: &Dhatu,
+    row: Row,
+) -> Result<(), Box<dyn Error>> {
+    for prayoga in PRAYOGAS {
+        // Filter prayoga based on args
+        if let Some(p) = args.prayoga {
+            if *prayoga != p {
+                continue;
+            }
+        }
+
+        // All tinantas for a lakara share the same dhatu, so derive them together.
+        let paradigm = v.derive_tinanta_paradigm(dhatu, *prayoga, None);
+        for lakara in LAKARA {
+            let table = match paradigm.get(*lakara) {
+                Some(t) => t,
+                None => continue,
+            };
+            for (purusha, vacana) in TIN_SEMANTICS {
+                let prakriyas = table.get(*purusha, *vacana);
+                let mut padas: Vec<_> = prakriyas.iter().map(|p| p.text()).collect();
+                padas.sort();
+                padas.dedup();
+                let padas = padas.join("|");
+                if padas.is_empty() {
+                    continue;
+                }
+
+                wtr.serialize(Row {
+                    padas,
+                    sanadi: row.sanadi.clone(),
+                    lakara: lakara.as_str(),
+                    purusha: purusha.as_str(),
+                    vacana: vacana.as_str(),
+                    prayoga: prayoga.as_str(),
+                    ..row
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn to_sanadi_str<'a>(sanadi: impl Iterator<Item = &'a Sanadi>) -> String {
+    sanadi.map(|x| x.as_str()).collect::<Vec<_>>().join("+")
+}
+
+fn run_dhatupatha(d: Dhatupatha, args: Args) -> Result<(), Box<dyn Error>> {
     let mut wtr = csv::Writer::from_writer(io::stdout());
     let v = Vyakarana::builder().log_steps(false).build();
 
-    let sanadi = match args.sanadi {
-        Some(x) => vec![x],
-        None => Vec::new(),
-    };
+    let sanadi = &args.sanadi;
 
     for entry in d {
         let dhatu = to_mula(entry.dhatu());
@@ -83,63 +162,53 @@ fn run(d: Dhatupatha, args: Args) -> Result<(), Box<dyn Error>> {
             .upadesha(dhatu.upadesha())
             .prefixes(dhatu.prefixes())
             .gana(dhatu.gana())
-            .sanadi(&sanadi);
+            .sanadi(sanadi);
         if let Some(x) = dhatu.antargana() {
             builder = builder.antargana(x);
         }
         let dhatu = builder.build()?;
         let mula = to_mula(&dhatu);
 
-        for prayoga in PRAYOGAS {
-            // Filter prayoga based on args
-            if let Some(p) = args.prayoga {
-                if *prayoga != p {
-                    continue;
-                }
-            }
+        let row = Row {
+            padas: String::new(),
+            dhatu: mula.upadesha(),
+            gana: mula.gana().as_str(),
+            number: entry.number(),
+            sanadi: to_sanadi_str(dhatu.sanadi().iter()),
+            prayoga: "",
+            lakara: "",
+            purusha: "",
+            vacana: "",
+        };
+        write_tinantas(&mut wtr, &v, &args, &dhatu, row)?;
+    }
 
-            for lakara in LAKARA {
-                for (purusha, vacana) in TIN_SEMANTICS {
-                    let tinanta = Tinanta::builder()
-                        .dhatu(dhatu.clone())
-                        .prayoga(*prayoga)
-                        .purusha(*purusha)
-                        .vacana(*vacana)
-                        .lakara(*lakara)
-                        .build()?;
-
-                    let prakriyas = v.derive_tinantas(&tinanta);
-                    let mut padas: Vec<_> = prakriyas.iter().map(|p| p.text()).collect();
-                    padas.sort();
-                    padas.dedup();
-                    let padas = padas.join("|");
-                    if padas.is_empty() {
-                        continue;
-                    }
-
-                    let sanadi_str = dhatu
-                        .sanadi()
-                        .iter()
-                        .map(|x| x.as_str())
-                        .fold(String::new(), |b, x| b + x + "+");
-                    let sanadi_str = sanadi_str.trim_end_matches('+');
-
-                    let dhatu_text = mula.upadesha();
-                    let row = Row {
-                        padas,
-                        dhatu: dhatu_text,
-                        gana: mula.gana().as_str(),
-                        number: entry.number(),
-                        sanadi: sanadi_str.to_string(),
-                        lakara: lakara.as_str(),
-                        purusha: purusha.as_str(),
-                        vacana: vacana.as_str(),
-                        prayoga: prayoga.as_str(),
-                    };
-
-                    wtr.serialize(row)?;
-                }
-            }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Writes the tinantas of each nAma-dhAtu. Since these rows have no gana or number, we leave
+/// these fields blank, and the `sanadi` field starts with the nAma-dhAtu pratyaya.
+fn run_namadhatus(args: Args) -> Result<(), Box<dyn Error>> {
+    let mut wtr = csv::Writer::from_writer(io::stdout());
+    let v = Vyakarana::builder().log_steps(false).build();
+
+    for pratipadika in NAMADHATU_PRATIPADIKAS {
+        for nama_sanadi in NAMA_SANADI {
+            let dhatu = Dhatu::nama(Pratipadika::basic(pratipadika), Some(*nama_sanadi))
+                .with_sanadi(&args.sanadi);
+            let row = Row {
+                padas: String::new(),
+                dhatu: pratipadika,
+                gana: "",
+                number: 0,
+                sanadi: to_sanadi_str(std::iter::once(nama_sanadi).chain(&args.sanadi)),
+                prayoga: "",
+                lakara: "",
+                purusha: "",
+                vacana: "",
+            };
+            write_tinantas(&mut wtr, &v, &args, &dhatu, row)?;
         }
     }
 
@@ -150,6 +219,14 @@ fn run(d: Dhatupatha, args: Args) -> Result<(), Box<dyn Error>> {
 fn main() {
     let args = Args::parse();
 
+    if args.namadhatus {
+        if let Err(err) = run_namadhatus(args) {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let dhatus = match Dhatupatha::from_path("data/dhatupatha.tsv") {
         Ok(res) => res,
         Err(err) => {
@@ -158,7 +235,7 @@ fn main() {
         }
     };
 
-    match run(dhatus, args) {
+    match run_dhatupatha(dhatus, args) {
         Ok(()) => (),
         Err(err) => {
             eprintln!("{}", err);
@@ -0,0 +1,336 @@
+/*!
+Derives words with `Vyakarana` and prints their prakriyas.
+
+A request can be given with flags:
+
+```text
+prakriya tinanta --dhatu BU --gana 1 --lakara lat --purusha prathama --vacana eka
+prakriya subanta --pratipadika deva --linga pum --vibhakti 1 --vacana eka
+prakriya krdanta --dhatu "qukf\\Y" --gana 8 --krt tfc
+prakriya taddhitanta --pratipadika upagu --taddhita aR
+```
+
+as a single JSON object:
+
+```text
+prakriya json '{"Tinanta": {"dhatu": {"Mula": {"upadesha": "BU", "gana": "Bhvadi"}}, ...}}'
+```
+
+or as a JSON Lines file with one request per line:
+
+```text
+prakriya batch requests.jsonl
+```
+
+For the JSON format, see the `Serialize` and `Deserialize` implementations in `args`.
+*/
+use clap::{Parser, Subcommand};
+use serde::Deserialize;
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use vidyut_lipi::{Lipika, Scheme};
+use vidyut_prakriya::args::{
+    BaseKrt, Dhatu, Gana, Krdanta, Krt, Lakara, Linga, Pada, Pratipadika, Prayoga, Purusha, Samasa,
+    Sanadi, Subanta, Taddhita, Taddhitanta, Tinanta, Unadi, Vacana, Vibhakti,
+};
+use vidyut_prakriya::{Prakriya, Rule, SvaraNotation, Vyakarana};
+
+#[derive(Parser)]
+#[command(author, version, about)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+
+    /// The script to print results in, e.g. `devanagari` or `iast`.
+    #[arg(long, default_value = "slp1", value_parser = parse_scheme)]
+    script: Scheme,
+
+    /// If set, print only the final results.
+    #[arg(long)]
+    no_history: bool,
+
+    /// If set, also use rules that apply only in Vedic Sanskrit.
+    #[arg(long)]
+    chandasi: bool,
+
+    /// If set, derive and print svaras.
+    #[arg(long)]
+    svaras: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Derives a tinanta.
+    Tinanta {
+        #[arg(long)]
+        dhatu: String,
+        #[arg(long)]
+        gana: Gana,
+        #[arg(long)]
+        prefix: Vec<String>,
+        #[arg(long)]
+        sanadi: Vec<Sanadi>,
+        #[arg(long, default_value = "kartari")]
+        prayoga: Prayoga,
+        #[arg(long)]
+        lakara: Lakara,
+        #[arg(long)]
+        purusha: Purusha,
+        #[arg(long)]
+        vacana: Vacana,
+    },
+    /// Derives a subanta from a basic pratipadika.
+    Subanta {
+        #[arg(long)]
+        pratipadika: String,
+        #[arg(long)]
+        linga: Linga,
+        #[arg(long)]
+        vibhakti: Vibhakti,
+        #[arg(long)]
+        vacana: Vacana,
+    },
+    /// Derives a krdanta. `--krt` accepts both krt-pratyayas and unadi-pratyayas.
+    Krdanta {
+        #[arg(long)]
+        dhatu: String,
+        #[arg(long)]
+        gana: Gana,
+        #[arg(long)]
+        prefix: Vec<String>,
+        #[arg(long)]
+        sanadi: Vec<Sanadi>,
+        #[arg(long, value_parser = parse_krt)]
+        krt: Krt,
+    },
+    /// Derives a taddhitanta from a basic pratipadika.
+    Taddhitanta {
+        #[arg(long)]
+        pratipadika: String,
+        #[arg(long)]
+        taddhita: Taddhita,
+    },
+    /// Derives a single request encoded as JSON.
+    Json { request: String },
+    /// Derives each request in a JSON Lines file.
+    Batch { path: PathBuf },
+}
+
+/// A derivation request.
+#[derive(Debug, Deserialize)]
+enum Request {
+    Dhatu(Dhatu),
+    Tinanta(Tinanta),
+    Subanta(Subanta),
+    Krdanta(Krdanta),
+    Taddhitanta(Taddhitanta),
+    Samasa(Samasa),
+    Vakya(Vec<Pada>),
+}
+
+fn parse_scheme(value: &str) -> Result<Scheme, String> {
+    let value = value.to_lowercase();
+    Scheme::iter()
+        .find(|s| format!("{s:?}").to_lowercase() == value)
+        .copied()
+        .ok_or_else(|| format!("unknown script `{value}`"))
+}
+
+fn parse_krt(value: &str) -> Result<Krt, String> {
+    if let Ok(k) = value.parse::<BaseKrt>() {
+        Ok(k.into())
+    } else if let Ok(k) = value.parse::<Unadi>() {
+        Ok(k.into())
+    } else {
+        Err(format!("unknown krt `{value}`"))
+    }
+}
+
+fn create_dhatu(upadesha: &str, gana: Gana, prefixes: &[String], sanadi: &[Sanadi]) -> Dhatu {
+    Dhatu::mula(upadesha, gana)
+        .with_prefixes(prefixes)
+        .with_sanadi(sanadi)
+}
+
+fn derive(v: &Vyakarana, request: &Request) -> Vec<Prakriya> {
+    match request {
+        Request::Dhatu(x) => v.derive_dhatus(x),
+        Request::Tinanta(x) => v.derive_tinantas(x),
+        Request::Subanta(x) => v.derive_subantas(x),
+        Request::Krdanta(x) => v.derive_krdantas(x),
+        Request::Taddhitanta(x) => v.derive_taddhitantas(x),
+        Request::Samasa(x) => v.derive_samasas(x),
+        Request::Vakya(x) => v.derive_vakyas(x),
+    }
+}
+
+fn rule_text(rule: Rule) -> String {
+    match rule {
+        Rule::Ashtadhyayi(x) => x.to_string(),
+        Rule::Varttika(x) => format!("vA {x}"),
+        Rule::Dhatupatha(x) => format!("DA {x}"),
+        Rule::Kashika(x) => format!("kA {x}"),
+        Rule::Kaumudi(x) => format!("kO {x}"),
+        Rule::Linganushasana(x) => format!("liNga {x}"),
+        Rule::Phit(x) => format!("Piw {x}"),
+        Rule::Unadipatha(x) => format!("uRA {x}"),
+    }
+}
+
+/// Prints prakriyas in the requested script.
+struct Printer {
+    lipika: Lipika,
+    script: Scheme,
+    svaras: bool,
+    history: bool,
+}
+
+impl Printer {
+    fn transliterate(&mut self, slp1: &str) -> String {
+        self.lipika.transliterate(slp1, Scheme::Slp1, self.script)
+    }
+
+    fn word(&mut self, p: &Prakriya) -> String {
+        if !self.svaras {
+            let text = p.text();
+            self.transliterate(&text)
+        } else if self.script == Scheme::Iast {
+            p.text_with_svaras(SvaraNotation::Iast)
+        } else {
+            let text = p.text_with_svaras(SvaraNotation::Slp1);
+            self.transliterate(&text)
+        }
+    }
+
+    fn print(&mut self, p: &Prakriya) {
+        println!("{}", self.word(p));
+        if !self.history {
+            return;
+        }
+
+        println!("---------------------------");
+        for step in p.history() {
+            // Skip debug messages.
+            if step.rule().code().trim().is_empty() {
+                continue;
+            }
+            // Keep svara markers only if the user asked for them.
+            let terms: Vec<_> = step
+                .result()
+                .iter()
+                .map(|t| match self.svaras {
+                    true => t.text().to_string(),
+                    false => t.text().replace(['/', '^', '\\'], ""),
+                })
+                .filter(|t| !t.is_empty())
+                .collect();
+            let result = self.transliterate(&terms.join(" + "));
+            println!("{:<10} | {}", rule_text(step.rule()), result);
+        }
+        println!("---------------------------");
+        println!();
+    }
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    let v = Vyakarana::builder()
+        .log_steps(!args.no_history)
+        .is_chandasi(args.chandasi)
+        .use_svaras(args.svaras)
+        .build();
+    let mut printer = Printer {
+        lipika: Lipika::new(),
+        script: args.script,
+        svaras: args.svaras,
+        history: !args.no_history,
+    };
+
+    let requests = match args.command {
+        Command::Tinanta {
+            dhatu,
+            gana,
+            prefix,
+            sanadi,
+            prayoga,
+            lakara,
+            purusha,
+            vacana,
+        } => {
+            let dhatu = create_dhatu(&dhatu, gana, &prefix, &sanadi);
+            let tinanta = Tinanta::new(dhatu, prayoga, lakara, purusha, vacana);
+            vec![(None, Request::Tinanta(tinanta))]
+        }
+        Command::Subanta {
+            pratipadika,
+            linga,
+            vibhakti,
+            vacana,
+        } => {
+            let pratipadika = Pratipadika::basic(pratipadika);
+            let subanta = Subanta::new(pratipadika, linga, vibhakti, vacana);
+            vec![(None, Request::Subanta(subanta))]
+        }
+        Command::Krdanta {
+            dhatu,
+            gana,
+            prefix,
+            sanadi,
+            krt,
+        } => {
+            let dhatu = create_dhatu(&dhatu, gana, &prefix, &sanadi);
+            vec![(None, Request::Krdanta(Krdanta::new(dhatu, krt)))]
+        }
+        Command::Taddhitanta {
+            pratipadika,
+            taddhita,
+        } => {
+            let taddhitanta = Taddhitanta::builder()
+                .pratipadika(Pratipadika::basic(pratipadika))
+                .taddhita(taddhita)
+                .build()?;
+            vec![(None, Request::Taddhitanta(taddhitanta))]
+        }
+        Command::Json { request } => vec![(None, serde_json::from_str(&request)?)],
+        Command::Batch { path } => {
+            let mut requests = Vec::new();
+            let reader = BufReader::new(File::open(path)?);
+            for (i, line) in reader.lines().enumerate() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str(&line) {
+                    Ok(r) => requests.push((Some(line), r)),
+                    Err(e) => return Err(format!("line {}: {}", i + 1, e).into()),
+                }
+            }
+            requests
+        }
+    };
+
+    for (line, request) in &requests {
+        // In batch mode, show which request produced the results below.
+        if let Some(line) = line {
+            println!("# {line}");
+        }
+        for p in derive(&v, request) {
+            printer.print(&p);
+        }
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args = Args::parse();
+
+    match run(args) {
+        Ok(()) => (),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
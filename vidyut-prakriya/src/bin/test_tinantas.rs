@@ -4,7 +4,7 @@ use rayon::prelude::*;
 use std::error::Error;
 use std::io::Write;
 use std::path::PathBuf;
-use vidyut_prakriya::args::{Dhatu, Sanadi, Tinanta};
+use vidyut_prakriya::args::{Dhatu, Pratipadika, Sanadi, Tinanta};
 use vidyut_prakriya::dhatupatha;
 use vidyut_prakriya::private::check_file_hash;
 use vidyut_prakriya::Vyakarana;
@@ -30,24 +30,23 @@ fn parse_sanadi(val: &str) -> Vec<Sanadi> {
     }
 }
 
-fn test_line(line: &str) -> Result<(), Box<dyn Error>> {
-    let mut reader = csv::ReaderBuilder::new()
-        .has_headers(false)
-        .from_reader(line.as_bytes());
-    let mut r = csv::StringRecord::new();
-    assert!(reader.read_record(&mut r).unwrap());
-
-    let expected: Vec<_> = r[0].split('|').collect();
+/// Parses the dhatu in `r`. Rows for nAma-dhAtus have no gana, and their `sanadi` field starts
+/// with the nAma-dhAtu pratyaya.
+fn parse_dhatu(r: &csv::StringRecord) -> Result<Dhatu, Box<dyn Error>> {
+    let sanadi = parse_sanadi(&r[4]);
+    if r[2].is_empty() {
+        let (nama_sanadi, other_sanadi) = match sanadi.split_first() {
+            Some((x, rest)) => (Some(*x), rest),
+            None => (None, &sanadi[..]),
+        };
+        let dhatu = Dhatu::nama(Pratipadika::basic(&r[1]), nama_sanadi);
+        return Ok(dhatu.with_sanadi(other_sanadi));
+    }
 
     let upadesha = &r[1];
     let gana = &r[2];
     let number = &r[3];
     let dhatu = dhatupatha::create_dhatu(upadesha, gana.parse()?, number.parse()?)?;
-    let sanadi = parse_sanadi(&r[4]);
-    let prayoga = r[5].parse()?;
-    let lakara = r[6].parse()?;
-    let purusha = r[7].parse()?;
-    let vacana = r[8].parse()?;
 
     // TODO: this is very clumsy!
     let mut builder = Dhatu::builder()
@@ -60,7 +59,23 @@ fn test_line(line: &str) -> Result<(), Box<dyn Error>> {
         builder = builder.antargana(x);
     }
 
-    let dhatu = builder.build()?;
+    Ok(builder.build()?)
+}
+
+fn test_line(line: &str) -> Result<(), Box<dyn Error>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(line.as_bytes());
+    let mut r = csv::StringRecord::new();
+    assert!(reader.read_record(&mut r).unwrap());
+
+    let expected: Vec<_> = r[0].split('|').collect();
+
+    let dhatu = parse_dhatu(&r)?;
+    let prayoga = r[5].parse()?;
+    let lakara = r[6].parse()?;
+    let purusha = r[7].parse()?;
+    let vacana = r[8].parse()?;
 
     let tinanta_args = Tinanta::builder()
         .dhatu(dhatu.clone())
@@ -80,8 +95,8 @@ fn test_line(line: &str) -> Result<(), Box<dyn Error>> {
         let lakara = &r[5];
         let purusha = &r[6];
         let vacana = &r[7];
-        let code = format!("{:0>2}.{:0>4}", gana, number);
-        let upadesha = dhatu.upadesha().expect("ok");
+        let code = format!("{:0>2}.{:0>4}", &r[2], &r[3]);
+        let upadesha = &r[1];
 
         let mut out = std::io::stdout().lock();
         writeln!(
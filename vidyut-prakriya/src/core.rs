@@ -4,6 +4,7 @@
 //!
 //! - `term`, which defines the `Term` struct
 //! - `prakriya`, which defines the `Prakriya` struct
+//! - `prakriya_diff`, which compares two `Prakriya`s
 
 pub mod char_view;
 pub mod errors;
@@ -12,12 +13,17 @@ pub mod operators;
 pub mod prakriya_stack;
 
 pub(crate) mod prakriya;
+pub(crate) mod prakriya_diff;
+pub(crate) mod rule_text;
+pub(crate) mod svara_text;
 pub(crate) mod tag;
 pub(crate) mod term;
 pub(crate) mod term_view;
 
 pub use errors::Error;
 pub use prakriya::*;
+pub use prakriya_diff::*;
+pub use svara_text::SvaraNotation;
 pub use tag::*;
 pub use term::*;
 pub use term_view::*;
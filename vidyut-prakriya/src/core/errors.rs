@@ -1,4 +1,5 @@
-use crate::core::RuleChoice;
+use crate::args::{DhatuPada, Krt, Sanadi, Taddhita};
+use crate::core::{Prakriya, Rule, RuleChoice};
 use std::fmt;
 use std::io;
 use std::num;
@@ -30,7 +31,119 @@ pub enum Error {
     InvalidUpadesha(String),
 
     /// The caller's arguments are incompatible with the prakriya, so we aborted early.
+    ///
+    /// This crate no longer returns this variant. It is kept so that existing matches on `Error`
+    /// still compile.
+    #[deprecated(note = "aborted derivations now return `Error::Aborted`")]
     Abort(Vec<RuleChoice>),
+
+    /// The caller's arguments are incompatible with the prakriya, so we aborted early.
+    Aborted {
+        /// Why the derivation was aborted.
+        reason: AbortReason,
+        /// The last rule applied before the derivation was aborted, if any.
+        last_rule: Option<Rule>,
+        /// The optional rules chosen before the derivation was aborted.
+        rule_choices: Vec<RuleChoice>,
+    },
+}
+
+/// Describes why a derivation was aborted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AbortReason {
+    /// The given pratipadika could not be made into a nama-dhatu.
+    NamadhatuNotCreated,
+    /// The given sanAdi-pratyaya could not be added to the dhatu.
+    SanadiNotAdded(Sanadi),
+    /// The given krt-pratyaya could not be added to the dhatu.
+    KrtNotAdded(Krt),
+    /// The given taddhita-pratyaya could not be added to the pratipadika.
+    TaddhitaNotAdded(Taddhita),
+    /// The given padas could not be made into a samasa.
+    SamasaNotCreated,
+    /// A nested krdanta or taddhitanta could not produce the text it was required to have.
+    RequiredTextNotDerived(String),
+}
+
+impl fmt::Display for AbortReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use AbortReason::*;
+
+        match self {
+            NamadhatuNotCreated => write!(f, "Could not create a nama-dhatu."),
+            SanadiNotAdded(s) => write!(f, "Could not add the sanAdi-pratyaya `{}`.", s.as_str()),
+            KrtNotAdded(k) => write!(f, "Could not add the krt-pratyaya `{}`.", k.as_str()),
+            TaddhitaNotAdded(t) => {
+                write!(f, "Could not add the taddhita-pratyaya `{}`.", t.as_str())
+            }
+            SamasaNotCreated => write!(f, "Could not create a samasa from the given padas."),
+            RequiredTextNotDerived(s) => write!(f, "Could not derive the required text `{s}`."),
+        }
+    }
+}
+
+/// Explains why some path through a derivation did not produce a result.
+///
+/// A derivation explores many combinations of optional rules, and each combination might fail for
+/// a different reason. So, we return one `Diagnostic` per failed combination.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Diagnostic {
+    /// The derivation was aborted because the arguments are incompatible with the rules applied.
+    Aborted {
+        /// Why the derivation was aborted.
+        reason: AbortReason,
+        /// The last rule applied before the derivation was aborted, if any.
+        last_rule: Option<Rule>,
+        /// The optional rules chosen before the derivation was aborted.
+        rule_choices: Vec<RuleChoice>,
+    },
+    /// The derivation failed because some argument is invalid, e.g. a malformed upadesha.
+    InvalidArgument(String),
+    /// The derivation succeeded, but its result does not use the pada the caller requested.
+    PadaMismatch {
+        /// The pada that the caller requested.
+        requested: DhatuPada,
+        /// The text of the derivation that we discarded.
+        text: String,
+    },
+}
+
+impl Diagnostic {
+    pub(crate) fn from_error(err: Error) -> Self {
+        match err {
+            Error::Aborted {
+                reason,
+                last_rule,
+                rule_choices,
+            } => Self::Aborted {
+                reason,
+                last_rule,
+                rule_choices,
+            },
+            _ => Self::InvalidArgument(err.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Diagnostic::*;
+
+        match self {
+            Aborted {
+                reason,
+                last_rule: Some(rule),
+                ..
+            } => write!(f, "{reason} The last rule applied was {}.", rule.code()),
+            Aborted { reason, .. } => write!(f, "{reason}"),
+            InvalidArgument(s) => write!(f, "{s}"),
+            PadaMismatch { requested, text } => write!(
+                f,
+                "`{text}` does not use the requested pada `{}`.",
+                requested.as_str()
+            ),
+        }
+    }
 }
 
 impl From<io::Error> for Error {
@@ -59,11 +172,20 @@ impl Error {
     pub(crate) fn invalid_upadesha(value: &str) -> Self {
         Error::InvalidUpadesha(value.to_string())
     }
+
+    pub(crate) fn abort(p: &Prakriya, reason: AbortReason) -> Self {
+        Error::Aborted {
+            reason,
+            last_rule: p.last_rule(),
+            rule_choices: p.rule_choices().clone(),
+        }
+    }
 }
 
 impl std::error::Error for Error {}
 
 impl fmt::Display for Error {
+    #[allow(deprecated)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Error::*;
 
@@ -76,6 +198,10 @@ impl fmt::Display for Error {
             MissingRequiredField(s) => write!(f, "Please define the `{s}` field."),
             ParseError(v) => write!(f, "Could not parse `{v}` into an enum value."),
             Abort(_) => write!(f, "The given arguments cannot produce a valid prakriya."),
+            Aborted { reason, .. } => write!(
+                f,
+                "The given arguments cannot produce a valid prakriya. {reason}"
+            ),
         }
     }
 }
@@ -5,10 +5,14 @@ Users interested in understanding this module should start by reading the commen
 `Prakriya` struct, which manages a derivation from start to finish.
 */
 use crate::args::{Artha, Lakara};
+use crate::core::rule_text;
+use crate::core::svara_text::{self, SvaraNotation};
 use crate::core::Tag;
-use crate::core::{Term, TermView};
+use crate::core::{PrakriyaDiff, Svara, Term, TermView};
 use compact_str::CompactString;
 use enumset::EnumSet;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
 
 /// A simple string label for some rule in the grammar.
 pub type Code = &'static str;
@@ -17,7 +21,7 @@ pub type Code = &'static str;
 ///
 /// Most of a derivation's rules come directly from the Ashtadhyayi. But, some derivations use
 /// rules from other sources. We use this model to clearly define where different rules come from.
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize)]
 pub enum Rule {
     /// A sutra from the Ashtadhyayi. The string data here is an adhyaya-pada-sutra string, e.g.
     /// "3.1.68".
@@ -57,6 +61,22 @@ impl Rule {
             Self::Kaumudi(x) => x,
         }
     }
+
+    /// The text of this rule, if available.
+    ///
+    /// We bundle the texts of the Ashtadhyayi, its varttikas, the Unadipatha, and the ganasutras
+    /// of the Dhatupatha. For other rules, this method returns `None`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::Rule;
+    /// assert_eq!(Rule::Ashtadhyayi("1.1.1").text(), Some("vfdDirAdEc"));
+    /// assert_eq!(Rule::Kashika("1.1.1").text(), None);
+    /// ```
+    pub fn text(&self) -> Option<&'static str> {
+        rule_text::get(*self)
+    }
 }
 
 // Since Ashtadhyayi rules are by far the most common, assume by default that static strings refer
@@ -74,7 +94,7 @@ impl From<&'static str> for Rule {
 /// structure with more information about the specific change. For example, we might explicitly
 /// indicate which term in the result was changed, which kind of rule was replied, and whether this
 /// rule was optional.
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize)]
 pub struct Step {
     rule: Rule,
     result: Vec<StepTerm>,
@@ -90,14 +110,32 @@ impl Step {
     pub fn result(&self) -> &Vec<StepTerm> {
         &self.result
     }
+
+    /// Renders the result of this step as a single string with svaras marked in the given
+    /// `notation`.
+    ///
+    /// Svaras are available only if the derivation was created with `use_svaras(true)`.
+    pub fn text_with_svaras(&self, notation: SvaraNotation) -> String {
+        // If a term has a svara, `StepTerm::text` already contains simple svara markers, so remove
+        // them first. Otherwise, keep the text as-is so that upadeshas keep their markers.
+        let terms: Vec<_> = self
+            .result
+            .iter()
+            .map(|t| match t.svara {
+                Some(_) => (t.text.replace(['/', '^', '\\'], ""), t.svara),
+                None => (t.text.clone(), None),
+            })
+            .collect();
+        svara_text::render(terms.iter().map(|(t, s)| (t.as_str(), *s)), notation)
+    }
 }
 
 /// One of the terms in the derivation.
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize)]
 pub struct StepTerm {
     text: String,
-    // NOTE: keep `tags` private.
     tags: EnumSet<Tag>,
+    svara: Option<Svara>,
     was_changed: bool,
 }
 
@@ -111,10 +149,29 @@ impl StepTerm {
     pub fn was_changed(&self) -> bool {
         self.was_changed
     }
+
+    /// Returns an iterator over the names of the tags on this term, such as `"Dhatu"` or
+    /// `"Pratyaya"`.
+    ///
+    /// Tags are an implementation detail of the derivation, so callers should not depend on the
+    /// exact tags returned here.
+    pub fn tags(&self) -> impl Iterator<Item = String> + '_ {
+        self.tags.iter().map(|t| format!("{t:?}"))
+    }
+
+    /// Returns whether this term has a tag with the given name, such as `"Dhatu"`.
+    pub fn has_tag(&self, name: &str) -> bool {
+        self.tags().any(|t| t == name)
+    }
+
+    /// The svara on this term, if one has been set.
+    pub fn svara(&self) -> Option<Svara> {
+        self.svara
+    }
 }
 
 /// Records whether an optional rule was accepted or declined.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
 pub enum RuleChoice {
     /// Indicates that a rule was accepted during the derivation.
     Accept(Rule),
@@ -122,6 +179,15 @@ pub enum RuleChoice {
     Decline(Rule),
 }
 
+impl RuleChoice {
+    /// The rule that was accepted or declined.
+    pub fn rule(&self) -> Rule {
+        match self {
+            Self::Accept(rule) | Self::Decline(rule) => *rule,
+        }
+    }
+}
+
 /// Configuration options that affect how a `Prakriya` behaves during the derivation.
 #[derive(Clone, Default, Debug)]
 pub(crate) struct Config {
@@ -166,7 +232,7 @@ impl Config {
 /// For example, we might want the derivation to use *chandasi* rules, or we might wish to block
 /// such rules. Or, we might want to skip history logging so that we can generate words more
 /// quickly.
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct Prakriya {
     terms: Vec<Term>,
     tags: EnumSet<Tag>,
@@ -175,6 +241,8 @@ pub struct Prakriya {
     config: Config,
     rule_choices: Vec<RuleChoice>,
     lakara: Option<Lakara>,
+    /// The last rule applied, which we track even if `log_steps` is false.
+    last_rule: Option<Rule>,
 }
 
 /// Public API
@@ -196,12 +264,46 @@ impl Prakriya {
         ret
     }
 
+    /// Returns a string representation of the current derivation state with svaras marked in the
+    /// given `notation`.
+    ///
+    /// Svaras are available only if the derivation was created with `use_svaras(true)`. Otherwise,
+    /// all vowels are treated as *anudAtta*.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::*;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::builder().use_svaras(true).build();
+    /// let args = Subanta::new(Pratipadika::basic("agni"), Linga::Pum, Vibhakti::Prathama, Vacana::Eka);
+    /// let prakriyas = v.derive_subantas(&args);
+    /// let p = &prakriyas[0];
+    /// assert_eq!(p.text_with_svaras(SvaraNotation::Slp1), "a\\gniH");
+    /// assert_eq!(p.text_with_svaras(SvaraNotation::Iast), "agni\u{301}ḥ");
+    /// ```
+    pub fn text_with_svaras(&self, notation: SvaraNotation) -> String {
+        svara_text::render(
+            self.terms
+                .iter()
+                .map(|t| (t.text.as_str(), t.svara.or(Some(Svara::Anudatta)))),
+            notation,
+        )
+    }
+
     /// Returns all of the optional rules that were encountered during the derivation and whether
     /// they were accepted or rejected.
     pub fn rule_choices(&self) -> &Vec<RuleChoice> {
         &self.rule_choices
     }
 
+    /// Returns the last rule applied during the derivation, if any.
+    ///
+    /// Unlike `history`, this is available even if history logging is disabled.
+    pub(crate) fn last_rule(&self) -> Option<Rule> {
+        self.last_rule
+    }
+
     /// Returns all of the rules that were applied during the derivation and the output of each
     /// step. If history logging has been disabled on `Vyakarana`, then `history()` will return
     /// an empty `Vec`.
@@ -214,6 +316,25 @@ impl Prakriya {
     pub fn artha(&self) -> Option<Artha> {
         self.artha
     }
+
+    /// Compares this derivation with `other` and explains where the two diverge.
+    ///
+    /// This is most useful for two derivations of the same input that produce different results.
+    /// For details, see `PrakriyaDiff`.
+    pub fn diff<'a>(&'a self, other: &'a Prakriya) -> PrakriyaDiff<'a> {
+        PrakriyaDiff::new(self, other)
+    }
+}
+
+// Serialize only the public API so that we can change `Prakriya`'s internals freely.
+impl Serialize for Prakriya {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Prakriya", 3)?;
+        state.serialize_field("text", &self.text())?;
+        state.serialize_field("history", &self.history)?;
+        state.serialize_field("rule_choices", &self.rule_choices)?;
+        state.end()
+    }
 }
 
 /// Private API
@@ -256,6 +377,7 @@ impl Prakriya {
             config: Config::new(),
             rule_choices: Vec::new(),
             lakara: None,
+            last_rule: None,
         }
     }
 
@@ -266,6 +388,14 @@ impl Prakriya {
         p
     }
 
+    /// Replaces the optional rule choices that this prakriya should follow.
+    ///
+    /// This is useful when resuming from a partial derivation. (See
+    /// `PrakriyaStack::find_all_branched`.)
+    pub(crate) fn set_config_rule_choices(&mut self, rule_choices: Vec<RuleChoice>) {
+        self.config.rule_choices = rule_choices;
+    }
+
     // Accessors
     // ---------
 
@@ -643,6 +773,24 @@ impl Prakriya {
         }
     }
 
+    /// Adds the given steps to the end of this prakriya's history.
+    ///
+    /// We use this to keep the history of a pada when we combine it into some vakya.
+    pub(crate) fn extend_history(&mut self, steps: &[Step]) {
+        if self.config.log_steps {
+            self.history.extend(steps.iter().cloned());
+        }
+    }
+
+    /// Adds the given rule choices to the start of this prakriya's rule choices.
+    ///
+    /// We use this to keep the rule choices of each pada once we have derived some vakya. We can't
+    /// add these choices before deriving the vakya, since `PrakriyaStack` would then try to
+    /// explore alternatives to them.
+    pub(crate) fn prepend_rule_choices(&mut self, choices: &[RuleChoice]) {
+        self.rule_choices.splice(0..0, choices.iter().copied());
+    }
+
     pub(crate) fn maybe_save_sthanivat(&mut self) {
         for i in 0..self.terms().len() {
             let t = self.get_mut(i).expect("ok");
@@ -755,6 +903,8 @@ impl Prakriya {
 
     /// Adds `rule` and the current derivation state to the derivation history.
     pub(crate) fn step(&mut self, rule: impl Into<Rule>) {
+        let rule = rule.into();
+        self.last_rule = Some(rule);
         if !self.config.log_steps {
             return;
         }
@@ -772,6 +922,7 @@ impl Prakriya {
                 StepTerm {
                     text: t.text_with_svaras(),
                     tags,
+                    svara: t.svara,
                     was_changed: false,
                 }
             })
@@ -803,10 +954,7 @@ impl Prakriya {
             result.iter_mut().for_each(|x| x.was_changed = true);
         }
 
-        self.history.push(Step {
-            rule: rule.into(),
-            result,
-        })
+        self.history.push(Step { rule, result })
     }
 
     // Optional rules
@@ -865,6 +1013,7 @@ impl Prakriya {
             result: vec![StepTerm {
                 text: text.as_ref().to_string(),
                 tags: EnumSet::new(),
+                svara: None,
                 was_changed: false,
             }],
         });
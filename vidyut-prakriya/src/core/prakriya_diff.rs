@@ -0,0 +1,287 @@
+//! Explains how two derivations of the same input diverge.
+//!
+//! Many inputs have more than one valid result because the Ashtadhyayi contains many optional
+//! rules. When two `Prakriya`s produce different results, we can compare their `rule_choices` to
+//! find the optional rule that separated them, then align their histories to see how that choice
+//! changed the derivation.
+use crate::core::{Prakriya, Rule, RuleChoice, Step, StepTerm};
+
+/// The first optional rule on which two derivations made different choices.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ChoiceDivergence {
+    index: usize,
+    first: Option<RuleChoice>,
+    second: Option<RuleChoice>,
+}
+
+impl ChoiceDivergence {
+    /// The index of this choice in both `rule_choices` lists.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The choice made by the first derivation, or `None` if the first derivation made no more
+    /// choices.
+    pub fn first(&self) -> Option<RuleChoice> {
+        self.first
+    }
+
+    /// The choice made by the second derivation, or `None` if the second derivation made no more
+    /// choices.
+    pub fn second(&self) -> Option<RuleChoice> {
+        self.second
+    }
+
+    /// The rule that the two derivations treated differently.
+    pub fn rule(&self) -> Option<Rule> {
+        self.first.or(self.second).map(|c| c.rule())
+    }
+}
+
+/// A term that differs between two aligned steps.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TermDiff<'a> {
+    index: usize,
+    first: Option<&'a StepTerm>,
+    second: Option<&'a StepTerm>,
+}
+
+impl<'a> TermDiff<'a> {
+    /// The index of this term in both steps.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The term in the first derivation, or `None` if the first step has no term at this index.
+    pub fn first(&self) -> Option<&'a StepTerm> {
+        self.first
+    }
+
+    /// The term in the second derivation, or `None` if the second step has no term at this index.
+    pub fn second(&self) -> Option<&'a StepTerm> {
+        self.second
+    }
+}
+
+/// A pair of aligned steps from two derivations.
+///
+/// If a rule was applied in only one of the derivations, the other side is `None`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StepDiff<'a> {
+    rule: Rule,
+    first: Option<&'a Step>,
+    second: Option<&'a Step>,
+    terms: Vec<TermDiff<'a>>,
+}
+
+impl<'a> StepDiff<'a> {
+    fn new(rule: Rule, first: Option<&'a Step>, second: Option<&'a Step>) -> Self {
+        let empty: &[StepTerm] = &[];
+        let xs = first.map_or(empty, |s| s.result());
+        let ys = second.map_or(empty, |s| s.result());
+
+        let terms = (0..xs.len().max(ys.len()))
+            .filter_map(|i| {
+                let x = xs.get(i);
+                let y = ys.get(i);
+                if x.map(|t| t.text()) == y.map(|t| t.text()) {
+                    None
+                } else {
+                    Some(TermDiff {
+                        index: i,
+                        first: x,
+                        second: y,
+                    })
+                }
+            })
+            .collect();
+
+        Self {
+            rule,
+            first,
+            second,
+            terms,
+        }
+    }
+
+    /// The step in the first derivation, if any.
+    pub fn first(&self) -> Option<&'a Step> {
+        self.first
+    }
+
+    /// The step in the second derivation, if any.
+    pub fn second(&self) -> Option<&'a Step> {
+        self.second
+    }
+
+    /// The rule applied in this step.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Returns whether the rule was applied in both derivations.
+    pub fn is_in_both(&self) -> bool {
+        self.first.is_some() && self.second.is_some()
+    }
+
+    /// The terms whose text differs between the two steps. If the rule was applied in only one
+    /// derivation, this contains all of that step's terms.
+    pub fn term_diffs(&self) -> &[TermDiff<'a>] {
+        &self.terms
+    }
+}
+
+/// Explains how two derivations diverge.
+///
+/// ### Example
+///
+/// ```
+/// # use vidyut_prakriya::*;
+/// # use vidyut_prakriya::args::*;
+/// let v = Vyakarana::new();
+/// let bhu = Dhatu::mula("BU", Gana::Bhvadi);
+/// let args = Tinanta::new(bhu, Prayoga::Kartari, Lakara::Lot, Purusha::Prathama, Vacana::Eka);
+/// let prakriyas = v.derive_tinantas(&args);
+/// let bhavatu = prakriyas.iter().find(|p| p.text() == "Bavatu").unwrap();
+/// let bhavatat = prakriyas.iter().find(|p| p.text() == "BavatAt").unwrap();
+///
+/// // Bavatu and BavatAt differ because of the optional rule 7.1.35.
+/// let diff = bhavatu.diff(bhavatat);
+/// let choice = diff.first_divergence().unwrap();
+/// assert_eq!(choice.first(), Some(RuleChoice::Decline(Rule::Ashtadhyayi("7.1.35"))));
+/// assert_eq!(choice.second(), Some(RuleChoice::Accept(Rule::Ashtadhyayi("7.1.35"))));
+///
+/// // The steps after the shared prefix show how that choice affected the derivation.
+/// let first_step = &diff.steps()[0];
+/// assert_eq!(first_step.rule(), Rule::Ashtadhyayi("7.1.35"));
+/// assert!(first_step.first().is_none());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrakriyaDiff<'a> {
+    divergence: Option<ChoiceDivergence>,
+    num_shared_steps: usize,
+    steps: Vec<StepDiff<'a>>,
+}
+
+impl<'a> PrakriyaDiff<'a> {
+    pub(crate) fn new(first: &'a Prakriya, second: &'a Prakriya) -> Self {
+        let divergence = find_divergence(first.rule_choices(), second.rule_choices());
+
+        let xs = first.history();
+        let ys = second.history();
+        let num_shared_steps = xs
+            .iter()
+            .zip(ys.iter())
+            .take_while(|(x, y)| is_same_step(x, y))
+            .count();
+        let steps = align(&xs[num_shared_steps..], &ys[num_shared_steps..]);
+
+        Self {
+            divergence,
+            num_shared_steps,
+            steps,
+        }
+    }
+
+    /// The first optional rule on which the two derivations made different choices, or `None` if
+    /// they made the same choices.
+    pub fn first_divergence(&self) -> Option<ChoiceDivergence> {
+        self.divergence
+    }
+
+    /// The number of steps at the start of both histories that are identical.
+    pub fn num_shared_steps(&self) -> usize {
+        self.num_shared_steps
+    }
+
+    /// The remaining steps of both histories, aligned by rule.
+    ///
+    /// If history logging was disabled, this is empty.
+    pub fn steps(&self) -> &[StepDiff<'a>] {
+        &self.steps
+    }
+}
+
+fn find_divergence(xs: &[RuleChoice], ys: &[RuleChoice]) -> Option<ChoiceDivergence> {
+    (0..xs.len().max(ys.len())).find_map(|i| {
+        let x = xs.get(i).copied();
+        let y = ys.get(i).copied();
+        if x == y {
+            None
+        } else {
+            Some(ChoiceDivergence {
+                index: i,
+                first: x,
+                second: y,
+            })
+        }
+    })
+}
+
+fn is_same_step(x: &Step, y: &Step) -> bool {
+    x.rule() == y.rule()
+        && x.result().len() == y.result().len()
+        && x.result()
+            .iter()
+            .zip(y.result().iter())
+            .all(|(a, b)| a.text() == b.text())
+}
+
+/// Aligns `xs` and `ys` by the longest common subsequence of their rules.
+fn align<'a>(xs: &'a [Step], ys: &'a [Step]) -> Vec<StepDiff<'a>> {
+    let n = xs.len();
+    let m = ys.len();
+
+    // lcs[i][j] is the length of the LCS of xs[i..] and ys[j..].
+    let mut lcs = vec![vec![0_usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if xs[i].rule() == ys[j].rule() {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ret = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && xs[i].rule() == ys[j].rule() {
+            ret.push(StepDiff::new(xs[i].rule(), Some(&xs[i]), Some(&ys[j])));
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ret.push(StepDiff::new(xs[i].rule(), Some(&xs[i]), None));
+            i += 1;
+        } else {
+            ret.push(StepDiff::new(ys[j].rule(), None, Some(&ys[j])));
+            j += 1;
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_divergence_with_different_choices() {
+        let a = RuleChoice::Accept(Rule::Ashtadhyayi("1.1.1"));
+        let b = RuleChoice::Accept(Rule::Ashtadhyayi("7.2.7"));
+        let c = RuleChoice::Decline(Rule::Ashtadhyayi("7.2.7"));
+
+        let d = find_divergence(&[a, b], &[a, c]).expect("diverges");
+        assert_eq!(d.index(), 1);
+        assert_eq!(d.first(), Some(b));
+        assert_eq!(d.second(), Some(c));
+        assert_eq!(d.rule(), Some(Rule::Ashtadhyayi("7.2.7")));
+
+        let d = find_divergence(&[a], &[a, c]).expect("diverges");
+        assert_eq!(d.first(), None);
+        assert_eq!(d.second(), Some(c));
+
+        assert_eq!(find_divergence(&[a, b], &[a, b]), None);
+    }
+}
@@ -14,9 +14,14 @@ pub(crate) struct PrakriyaStack {
     is_chandasi: bool,
     /// Whether svara rules are enabled.
     use_svaras: bool,
+    /// Optional rules that the caller has already decided. We never explore alternatives to these
+    /// choices.
+    fixed_choices: Vec<RuleChoice>,
 
     /// Completed prakriyas.
     prakriyas: Vec<Prakriya>,
+    /// Explanations for the paths that did not produce a prakriya.
+    diagnostics: Vec<Diagnostic>,
     /// Combinations of optional rules that we have yet to try.
     paths: Vec<Vec<RuleChoice>>,
 }
@@ -26,46 +31,138 @@ impl PrakriyaStack {
     pub fn new(log_steps: bool, is_chandasi: bool, use_svaras: bool) -> Self {
         Self {
             prakriyas: Vec::new(),
+            diagnostics: Vec::new(),
             paths: Vec::new(),
             log_steps,
             is_chandasi,
             use_svaras,
+            fixed_choices: Vec::new(),
         }
     }
 
+    /// Sets the optional rules that every prakriya must accept or decline.
+    pub fn with_fixed_choices(mut self, choices: Vec<RuleChoice>) -> Self {
+        self.fixed_choices = choices;
+        self
+    }
+
     /// Creates a new `Prakriya` according to upstream options.
-    fn new_prakriya(&self, rule_choices: Vec<RuleChoice>) -> Prakriya {
+    fn new_prakriya(&self, path: &[RuleChoice]) -> Prakriya {
         Prakriya::with_config(Config {
-            rule_choices,
+            rule_choices: self.config_choices(path),
             log_steps: self.log_steps,
             is_chandasi: self.is_chandasi,
             use_svaras: self.use_svaras,
         })
     }
 
+    /// Creates an empty stack with the same options as this one.
+    fn new_empty(&self) -> Self {
+        Self::new(self.log_steps, self.is_chandasi, self.use_svaras)
+            .with_fixed_choices(self.fixed_choices.clone())
+    }
+
     /// Finds all variants of the given derivation function.
     ///
-    /// `derive` should accept an empty `Prakriya` and mutate it in-place.
+    /// `derive` should accept an empty `Prakriya` and mutate it in-place. We run `derive` from the
+    /// start for every combination of optional rules, since our rule functions can't be resumed
+    /// from the middle of a derivation. To share a common prefix, use `find_all_branched`.
     pub fn find_all(&mut self, derive: impl Fn(Prakriya) -> Result<Prakriya>) {
         self.paths.push(vec![]);
 
         while let Some(path) = self.pop_path() {
-            let p_init = self.new_prakriya(path.clone());
+            let p_init = self.new_prakriya(&path);
             match derive(p_init) {
                 Ok(p) => {
                     self.add_new_paths(p.rule_choices(), &path);
                     self.prakriyas.push(p);
                 }
-                Err(e) => {
-                    if let Error::Abort(choices) = e {
-                        self.add_new_paths(&choices, &path);
-                    }
-                    // TODO: handle other errors better.
+                Err(e) => self.add_error(e, &path),
+            }
+        }
+    }
+
+    /// Like `find_all`, but shares the work in `prefix` across several derivations.
+    ///
+    /// We first find all variants of `prefix`. Then for each of these partial derivations and
+    /// each function in `branches`, we resume from that partial derivation and find all variants
+    /// of the branch. The stack at index `i` of the result has the same prakriyas as a call to
+    /// `find_all` that runs `prefix` and then `branches[i]`, but we run `prefix` only once.
+    ///
+    /// This is useful for paradigms, where (for example) all nine tinantas for some lakara use the
+    /// same dhatu.
+    pub fn find_all_branched<B>(
+        &self,
+        prefix: impl Fn(&mut Prakriya) -> Result<()>,
+        branches: &[B],
+    ) -> Vec<Self>
+    where
+        B: Fn(&mut Prakriya) -> Result<()>,
+    {
+        let mut base = self.new_empty();
+        base.find_all(|mut p| {
+            prefix(&mut p)?;
+            Ok(p)
+        });
+
+        branches
+            .iter()
+            .map(|branch| {
+                let mut stack = self.new_empty();
+                stack.diagnostics = base.diagnostics.clone();
+                for p in &base.prakriyas {
+                    stack.find_all_from(p, branch);
+                }
+                stack
+            })
+            .collect()
+    }
+
+    /// Finds all variants of `derive` that continue the partial derivation `p_start`.
+    ///
+    /// `derive` should accept a copy of `p_start` and mutate it in-place. We explore only the
+    /// optional rules that `derive` encounters, since `p_start` has already made its choices.
+    fn find_all_from(&mut self, p_start: &Prakriya, derive: impl Fn(&mut Prakriya) -> Result<()>) {
+        self.paths.push(p_start.rule_choices().clone());
+
+        while let Some(path) = self.pop_path() {
+            let mut p = p_start.clone();
+            p.set_config_rule_choices(self.config_choices(&path));
+            match derive(&mut p) {
+                Ok(()) => {
+                    self.add_new_paths(p.rule_choices(), &path);
+                    self.prakriyas.push(p);
                 }
+                Err(e) => self.add_error(e, &path),
             }
         }
     }
 
+    /// Records why the derivation on `path` failed.
+    ///
+    /// If the derivation aborted, we still explore the optional rules it chose before aborting,
+    /// since other combinations of these rules might succeed.
+    fn add_error(&mut self, err: Error, path: &[RuleChoice]) {
+        if let Error::Aborted {
+            rule_choices: choices,
+            ..
+        } = &err
+        {
+            self.add_new_paths(choices, path);
+        }
+        self.diagnostics.push(Diagnostic::from_error(err));
+    }
+
+    /// Returns the rule choices that a prakriya on `path` should follow.
+    ///
+    /// `Prakriya` uses the first choice it finds for a rule, so we place `fixed_choices` first to
+    /// give them priority over the current path.
+    fn config_choices(&self, path: &[RuleChoice]) -> Vec<RuleChoice> {
+        let mut rule_choices = self.fixed_choices.clone();
+        rule_choices.extend(path);
+        rule_choices
+    }
+
     /// Adds new paths to the stack.
     ///
     /// We find new paths as follows. Suppose our initial prakriya followed the following path:
@@ -91,6 +188,11 @@ impl PrakriyaStack {
     fn add_new_paths(&mut self, choices: &Vec<RuleChoice>, initial_choices: &[RuleChoice]) {
         let offset = initial_choices.len();
         for i in offset..choices.len() {
+            // The caller has fixed this choice, so don't try the alternative.
+            if self.is_fixed(choices[i]) {
+                continue;
+            }
+
             let mut path = choices[..=i].to_vec();
 
             // Swap the last choice.
@@ -104,6 +206,12 @@ impl PrakriyaStack {
         }
     }
 
+    /// Returns whether the caller has fixed the rule for `choice`.
+    fn is_fixed(&self, choice: RuleChoice) -> bool {
+        let rule = choice.rule();
+        self.fixed_choices.iter().any(|c| c.rule() == rule)
+    }
+
     /// Pops an unexplored choice path from the stack.
     fn pop_path(&mut self) -> Option<Vec<RuleChoice>> {
         self.paths.pop()
@@ -113,4 +221,59 @@ impl PrakriyaStack {
     pub fn prakriyas(self) -> Vec<Prakriya> {
         self.prakriyas
     }
+
+    /// Returns all of the prakriyas this stack has completed along with diagnostics for the paths
+    /// that failed.
+    pub fn prakriyas_and_diagnostics(self) -> (Vec<Prakriya>, Vec<Diagnostic>) {
+        (self.prakriyas, self.diagnostics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::args::{Dhatu, Gana, Lakara, Prayoga, Purusha, Sanadi, Tinanta, Vacana};
+    use crate::ashtadhyayi;
+
+    fn texts(stack: PrakriyaStack) -> Vec<String> {
+        let mut ret: Vec<_> = stack.prakriyas().iter().map(|p| p.text()).collect();
+        ret.sort();
+        ret
+    }
+
+    #[test]
+    fn find_all_branched_matches_find_all() {
+        let dhatu = Dhatu::mula("nATf~\\", Gana::Bhvadi).with_sanadi(&[Sanadi::san]);
+        let (prayoga, lakara) = (Prayoga::Kartari, Lakara::Lit);
+        let cells = [
+            (Purusha::Prathama, Vacana::Eka),
+            (Purusha::Madhyama, Vacana::Dvi),
+            (Purusha::Uttama, Vacana::Bahu),
+        ];
+
+        let stack = PrakriyaStack::new(false, false, false);
+        let branches: Vec<_> = cells
+            .iter()
+            .map(|(purusha, vacana)| {
+                move |p: &mut Prakriya| {
+                    ashtadhyayi::finish_tinanta(p, prayoga, lakara, *purusha, *vacana)
+                }
+            })
+            .collect();
+        let actual = stack.find_all_branched(
+            |p| ashtadhyayi::prepare_tinanta_dhatu(p, &dhatu, prayoga, lakara),
+            &branches,
+        );
+
+        assert_eq!(actual.len(), cells.len());
+        for ((purusha, vacana), actual) in cells.iter().zip(actual) {
+            let args = Tinanta::new(dhatu.clone(), prayoga, lakara, *purusha, *vacana);
+            let mut expected = PrakriyaStack::new(false, false, false);
+            expected.find_all(|p| ashtadhyayi::derive_tinanta(p, &args));
+
+            let expected = texts(expected);
+            assert!(expected.len() > 1);
+            assert_eq!(texts(actual), expected);
+        }
+    }
 }
@@ -0,0 +1,68 @@
+//! Looks up the text of the rules used in a derivation.
+//!
+//! We bundle the texts below with the crate so that `Rule::text` works in any environment,
+//! including WebAssembly.
+use crate::core::Rule;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+type TextMap = HashMap<&'static str, &'static str>;
+
+lazy_static! {
+    static ref SUTRAPATHA: TextMap = parse(include_str!("../../data/sutrapatha.tsv"));
+    static ref VARTTIKAS: TextMap = parse(include_str!("../../data/varttikas.tsv"));
+    static ref UNADIPATHA: TextMap = parse(include_str!("../../data/unadipatha.tsv"));
+    static ref DHATUPATHA_GANASUTRAS: TextMap =
+        parse(include_str!("../../data/dhatupatha-ganasutras.tsv"));
+}
+
+/// Parses a headerless TSV whose first column is a rule code and whose second column is the
+/// rule's text.
+fn parse(tsv: &'static str) -> TextMap {
+    tsv.lines()
+        .filter_map(|line| line.split_once('\t'))
+        .collect()
+}
+
+/// Returns the text of the given rule, if available.
+pub(crate) fn get(rule: Rule) -> Option<&'static str> {
+    match rule {
+        Rule::Ashtadhyayi(code) => SUTRAPATHA.get(code).copied(),
+        Rule::Varttika(code) => {
+            // Some codes have a suffix that distinguishes different parts of the same varttika,
+            // e.g. "3.3.125.1:2".
+            let code = code.split_once(':').map_or(code, |(x, _)| x);
+            VARTTIKAS.get(code).copied()
+        }
+        Rule::Unadipatha(code) => UNADIPATHA.get(code).copied(),
+        Rule::Dhatupatha(code) => DHATUPATHA_GANASUTRAS.get(code).copied(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_bundled_texts() {
+        assert_eq!(get(Rule::Ashtadhyayi("1.1.1")), Some("vfdDirAdEc"));
+        assert_eq!(
+            get(Rule::Varttika("1.1.33.1")),
+            Some("viBAzAprakaraRe tIyasya NitsUpasaNKyAnam")
+        );
+        assert!(get(Rule::Varttika("3.3.125.1:2")).is_some());
+        assert_eq!(
+            get(Rule::Unadipatha("1.1")),
+            Some("kfvApAjimisvadisADyaSUBya uR")
+        );
+        assert_eq!(get(Rule::Dhatupatha("01.0933")), Some("GawAdayo mitaH"));
+    }
+
+    #[test]
+    fn get_missing_texts() {
+        assert_eq!(get(Rule::Ashtadhyayi("9.9.9")), None);
+        assert_eq!(get(Rule::Kashika("1.1.1")), None);
+        assert_eq!(get(Rule::Kaumudi("446")), None);
+    }
+}
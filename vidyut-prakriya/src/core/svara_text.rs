@@ -0,0 +1,212 @@
+//! Renders accented text in various notations.
+use crate::core::term::Svara;
+use crate::sounds;
+#[cfg(feature = "lipi")]
+use lazy_static::lazy_static;
+#[cfg(feature = "lipi")]
+use vidyut_lipi::{Mapping, Scheme};
+
+#[cfg(feature = "lipi")]
+lazy_static! {
+    static ref SLP1_TO_DEVANAGARI: Mapping = Mapping::new(Scheme::Slp1, Scheme::Devanagari);
+    static ref SLP1_TO_IAST: Mapping = Mapping::new(Scheme::Slp1, Scheme::Iast);
+}
+
+/// A notation for rendering svaras.
+///
+/// All notations follow the Rgvedic convention of leaving the *udAtta* unmarked or marking it
+/// with an acute accent, and they all apply *udAttAd anudAttasya svaritaH* (8.4.66) across
+/// the whole word.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SvaraNotation {
+    /// SLP1 with `\` after an *anudAtta* vowel and `^` after a *svarita* vowel. The *udAtta* is
+    /// unmarked, as are *anudAtta* vowels that follow a *svarita* (*pracaya*).
+    ///
+    /// Example: `a\gniH`
+    Slp1,
+    /// Devanagari with the Vedic *anudAtta* (U+0952) and *svarita* (U+0951) marks, using the same
+    /// marking scheme as `Slp1`.
+    ///
+    /// Example: `अ॒ग्निः`
+    ///
+    /// Requires the `lipi` feature.
+    #[cfg(feature = "lipi")]
+    Devanagari,
+    /// IAST with an acute accent on the *udAtta* and a grave accent on an independent *svarita*.
+    /// Dependent *svarita* vowels are unmarked.
+    ///
+    /// Example: `agníḥ`
+    ///
+    /// Requires the `lipi` feature.
+    #[cfg(feature = "lipi")]
+    Iast,
+}
+
+/// The accent of a single vowel.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Accent {
+    Udatta,
+    Anudatta,
+    /// A *svarita* that the grammar assigns directly, e.g. through 6.1.185.
+    Svarita,
+    /// A *svarita* created by 8.4.66.
+    DependentSvarita,
+    /// A vowel in a term that has no svara yet. We leave the text of such terms as-is.
+    Unmarked,
+}
+
+/// Returns the accent of each vowel in `terms`.
+fn vowel_accents<'a>(terms: impl Iterator<Item = (&'a str, Option<Svara>)>) -> Vec<Accent> {
+    let mut ret = Vec::new();
+    for (text, svara) in terms {
+        let num_vowels = text.chars().filter(|c| sounds::is_ac(*c)).count();
+        for i in 0..num_vowels {
+            let accent = match svara {
+                Some(Svara::Udatta(j)) if i == j => Accent::Udatta,
+                Some(Svara::Svarita(j)) if i == j => Accent::Svarita,
+                Some(_) => Accent::Anudatta,
+                None => Accent::Unmarked,
+            };
+            ret.push(accent);
+        }
+    }
+
+    // 8.4.66 udAttAd anudAttasya svaritaH
+    for i in 1..ret.len() {
+        if ret[i - 1] == Accent::Udatta && ret[i] == Accent::Anudatta {
+            ret[i] = Accent::DependentSvarita;
+        }
+    }
+    ret
+}
+
+/// Returns the SLP1 marker that follows the vowel at index `i`.
+fn slp1_marker(accents: &[Accent], i: usize) -> &'static str {
+    match accents[i] {
+        Accent::Udatta | Accent::Unmarked => "",
+        Accent::Svarita | Accent::DependentSvarita => "^",
+        Accent::Anudatta => {
+            let is_before_peak = accents[..i]
+                .iter()
+                .all(|a| matches!(a, Accent::Anudatta | Accent::Unmarked));
+            let is_before_raised = matches!(
+                accents.get(i + 1),
+                Some(Accent::Udatta) | Some(Accent::Svarita)
+            );
+            if is_before_peak || is_before_raised {
+                "\\"
+            } else {
+                ""
+            }
+        }
+    }
+}
+
+/// Returns the IAST combining mark that follows the vowel at index `i`.
+#[cfg(feature = "lipi")]
+fn iast_marker(accents: &[Accent], i: usize) -> &'static str {
+    match accents[i] {
+        Accent::Udatta => "\u{0301}",
+        Accent::Svarita => "\u{0300}",
+        _ => "",
+    }
+}
+
+/// Renders `terms` as a single string with svaras marked in the given `notation`.
+///
+/// Terms with no svara are copied as-is, so any markers they already contain (e.g. in an upadesha
+/// like `qupa\ca~^z`) are preserved.
+pub(crate) fn render<'a>(
+    terms: impl Iterator<Item = (&'a str, Option<Svara>)> + Clone,
+    notation: SvaraNotation,
+) -> String {
+    let accents = vowel_accents(terms.clone());
+    let marker = match notation {
+        SvaraNotation::Slp1 => slp1_marker,
+        #[cfg(feature = "lipi")]
+        SvaraNotation::Devanagari => slp1_marker,
+        #[cfg(feature = "lipi")]
+        SvaraNotation::Iast => iast_marker,
+    };
+
+    let mut slp1 = String::new();
+    let mut i_vowel = 0;
+    for (text, _) in terms {
+        for c in text.chars() {
+            slp1.push(c);
+            if sounds::is_ac(c) {
+                slp1.push_str(marker(&accents, i_vowel));
+                i_vowel += 1;
+            }
+        }
+    }
+
+    match notation {
+        SvaraNotation::Slp1 => slp1,
+        #[cfg(feature = "lipi")]
+        SvaraNotation::Devanagari => vidyut_lipi::transliterate(&slp1, &SLP1_TO_DEVANAGARI),
+        #[cfg(feature = "lipi")]
+        SvaraNotation::Iast => vidyut_lipi::transliterate(&slp1, &SLP1_TO_IAST),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Svara::*;
+
+    fn slp1(terms: &[(&str, Option<Svara>)]) -> String {
+        render(terms.iter().copied(), SvaraNotation::Slp1)
+    }
+
+    #[test]
+    fn render_slp1() {
+        // agni/H
+        assert_eq!(
+            slp1(&[("agni", Some(Udatta(1))), ("H", Some(Anudatta))]),
+            "a\\gniH"
+        );
+        // a/Svena: the vowel after the udAtta becomes svarita, and the rest is pracaya.
+        assert_eq!(slp1(&[("aSvena", Some(Udatta(0)))]), "aSve^na");
+        // rAjanya^H
+        assert_eq!(
+            slp1(&[("rAjanya", Some(Svarita(2))), ("H", Some(Anudatta))]),
+            "rA\\ja\\nya^H"
+        );
+    }
+
+    #[test]
+    fn render_slp1_with_unaccented_terms() {
+        // Terms without a svara keep their upadesha markers.
+        assert_eq!(
+            slp1(&[("qupa\\ca~^z", None), ("Sap", Some(Anudatta))]),
+            "qupa\\ca~^zSa\\p"
+        );
+    }
+
+    #[cfg(feature = "lipi")]
+    #[test]
+    fn render_iast() {
+        let terms = [("agni", Some(Udatta(1))), ("H", Some(Anudatta))];
+        assert_eq!(
+            render(terms.iter().copied(), SvaraNotation::Iast),
+            "agni\u{301}ḥ"
+        );
+
+        let terms = [("kanyA", Some(Svarita(1)))];
+        assert_eq!(
+            render(terms.iter().copied(), SvaraNotation::Iast),
+            "kanyā\u{300}"
+        );
+    }
+
+    #[cfg(feature = "lipi")]
+    #[test]
+    fn render_devanagari() {
+        let terms = [("agni", Some(Udatta(1)))];
+        assert_eq!(
+            render(terms.iter().copied(), SvaraNotation::Devanagari),
+            "अ॒ग्नि"
+        );
+    }
+}
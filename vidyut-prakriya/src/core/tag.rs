@@ -1,5 +1,6 @@
 use crate::core::errors::*;
 use enumset::EnumSetType;
+use serde::{Deserialize, Serialize};
 
 /// An annotation on some `Term`.
 ///
@@ -16,7 +17,8 @@ use enumset::EnumSetType;
 /// conventions. Doing so lets us more easily distinguish among `Tag`s like `Nit`, `Yit`, Rit`, and
 /// `nit`.
 #[allow(non_camel_case_types)]
-#[derive(Debug, EnumSetType)]
+#[derive(Debug, EnumSetType, Serialize, Deserialize)]
+#[enumset(serialize_repr = "list")]
 pub enum Tag {
     // Morpheme types
     Upasarga,
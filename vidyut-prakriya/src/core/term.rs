@@ -6,6 +6,7 @@ use crate::sounds::{s, Set};
 use enumset::EnumSet;
 
 use lazy_static::lazy_static;
+use serde::Serialize;
 
 // Abstracts our choice of String type so we can try other implementations.
 //
@@ -35,9 +36,9 @@ lazy_static! {
     static ref AC: Set = s("ac");
 }
 
-/// Models the svaras on a particular `Term`.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub(crate) enum Svara {
+/// Models the svaras on a particular term.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+pub enum Svara {
     /// Indicates that the entire `Term` has the *anudAtta* accent.
     Anudatta,
     /// Indicates that the `Term` has the *udAtta* accent on the specified vowel. If the first
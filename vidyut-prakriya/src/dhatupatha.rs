@@ -5,17 +5,73 @@ comments on the `Dhatupatha` struct.
 
 use crate::args::{Antargana, Dhatu, Gana};
 use crate::core::errors::*;
+use crate::core::{Prakriya, Tag, Term};
+use crate::dhatu_karya;
+use crate::enum_boilerplate;
+use crate::it_agama;
 use std::path::Path;
 
+/// A traditional text whose dhatupatha is included in the ashtadhyayi.com dhatupatha.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Source {
+    /// The *Siddhāntakaumudī*.
+    Siddhantakaumudi,
+    /// The *Bṛhaddhātukusumākaraḥ*.
+    Brhaddhatukusumakara,
+    /// The *Mādhavīyadhātuvṛttiḥ*.
+    Madhaviya,
+    /// The *Kṣīrataraṅgiṇī*.
+    Kshiratarangini,
+    /// The *Dhātupradīpaḥ*.
+    Dhatupradipa,
+}
+
+enum_boilerplate!(Source, {
+    Siddhantakaumudi => "kaumudi",
+    Brhaddhatukusumakara => "kusumakara",
+    Madhaviya => "madhaviya",
+    Kshiratarangini => "kshiratarangini",
+    Dhatupradipa => "dhatupradipa",
+});
+
+/// Whether a dhatu takes it-Agama before a valAdi ArdhadhAtuka pratyaya.
+///
+/// This is the dhatu's default behavior as determined by its upadesha. Specific pratyayas often
+/// override it (e.g. 7.2.11 for kit pratyayas).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ItStatus {
+    /// The dhatu takes it-Agama.
+    Set,
+    /// The dhatu blocks it-Agama per 7.2.10.
+    Anit,
+    /// The dhatu takes it-Agama optionally per 7.2.44.
+    Vet,
+}
+
+/// The padas that a dhatu takes in kartari prayoga.
+///
+/// This is the dhatu's default behavior as determined by its upadesha. Certain upasargas and
+/// arthas override it (e.g. 1.3.19 for vi + ji).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Padi {
+    /// The dhatu takes parasmaipada endings per 1.3.78.
+    Parasmaipadi,
+    /// The dhatu takes Atmanepada endings per 1.3.12.
+    Atmanepadi,
+    /// The dhatu takes either set of endings per 1.3.72.
+    Ubhayapadi,
+}
+
 /// An entry in the Dhatupatha.
 pub struct Entry {
     code: String,
     dhatu: Dhatu,
     artha: String,
+    sources: Vec<Source>,
 }
 
 impl Entry {
-    fn parse(code: &str, upadesha: &str, artha: &str) -> Result<Self> {
+    fn parse(code: &str, upadesha: &str, artha: &str, sources: &str) -> Result<Self> {
         let (gana, number) = code.split_once('.').ok_or(Error::InvalidFile)?;
         let gana = if let Some(stripped) = gana.strip_prefix('0') {
             stripped.parse()?
@@ -24,11 +80,19 @@ impl Entry {
         };
         let number = number.parse()?;
         let dhatu = create_dhatu(upadesha, gana, number)?;
+        let sources = match sources {
+            "" | "-" => Vec::new(),
+            _ => sources
+                .split(',')
+                .map(|x| x.trim().parse())
+                .collect::<Result<_>>()?,
+        };
 
         Ok(Self {
             code: code.to_string(),
             dhatu,
             artha: artha.to_string(),
+            sources,
         })
     }
 
@@ -47,6 +111,51 @@ impl Entry {
         &self.artha
     }
 
+    /// The sources that attest this entry.
+    ///
+    /// This list is empty if the Dhatupatha was loaded without source information.
+    pub fn sources(&self) -> &[Source] {
+        &self.sources
+    }
+
+    /// Returns whether `source` attests this entry.
+    pub fn is_attested_in(&self, source: Source) -> bool {
+        self.sources.contains(&source)
+    }
+
+    /// Returns whether the dhatu has the given *it* or accent tag after it-samjna (e.g.
+    /// `Tag::Nit`, `Tag::anudattet`, or `Tag::Anudatta`).
+    pub fn has_tag(&self, tag: Tag) -> bool {
+        self.create_term().map_or(false, |t| t.has_tag(tag))
+    }
+
+    /// Returns whether the dhatu takes it-Agama by default.
+    pub fn it_status(&self) -> ItStatus {
+        match self.create_term() {
+            Some(t) => it_status(&t),
+            None => ItStatus::Set,
+        }
+    }
+
+    /// Returns the padas that the dhatu takes by default.
+    pub fn padi(&self) -> Padi {
+        match self.create_term() {
+            Some(t) => padi(&t),
+            None => Padi::Parasmaipadi,
+        }
+    }
+
+    /// Creates the dhatu's term after it-samjna.
+    fn create_term(&self) -> Option<Term> {
+        let mula = match &self.dhatu {
+            Dhatu::Mula(m) => m,
+            Dhatu::Nama(_) => return None,
+        };
+        let mut p = Prakriya::new();
+        dhatu_karya::run(&mut p, mula).ok()?;
+        p.terms().iter().find(|t| t.is_dhatu()).cloned()
+    }
+
     /// Returns the position of this entry within the gana.
     pub fn number(&self) -> u16 {
         let (_gana, number) = self.code.split_once('.').expect("should have been checked");
@@ -54,6 +163,168 @@ impl Entry {
     }
 }
 
+fn it_status(t: &Term) -> ItStatus {
+    // 7.2.44 is an exception to 7.2.10, so check it first.
+    if it_agama::is_svarati_suti(t) {
+        ItStatus::Vet
+    } else if it_agama::is_ekac_anudatta(t) {
+        ItStatus::Anit
+    } else {
+        ItStatus::Set
+    }
+}
+
+fn padi(t: &Term) -> Padi {
+    if t.has_tag_in(&[Tag::anudattet, Tag::Nit]) {
+        Padi::Atmanepadi
+    } else if t.has_tag_in(&[Tag::svaritet, Tag::Yit]) {
+        Padi::Ubhayapadi
+    } else {
+        Padi::Parasmaipadi
+    }
+}
+
+/// Normalizes SLP1 text for loose matching by removing vowel length, aspiration, doubled sounds,
+/// and the distinctions among sibilants and among nasals.
+fn normalize_artha(text: &str) -> String {
+    let mut ret: Vec<char> = text
+        .chars()
+        .filter_map(|c| match c {
+            'A' => Some('a'),
+            'I' => Some('i'),
+            'U' => Some('u'),
+            'F' => Some('f'),
+            'X' => Some('x'),
+            'K' => Some('k'),
+            'G' => Some('g'),
+            'C' => Some('c'),
+            'J' => Some('j'),
+            'W' => Some('w'),
+            'Q' => Some('q'),
+            'T' => Some('t'),
+            'D' => Some('d'),
+            'P' => Some('p'),
+            'B' => Some('b'),
+            'S' | 'z' => Some('s'),
+            'N' | 'Y' | 'R' => Some('n'),
+            'M' => Some('m'),
+            'H' | ' ' => None,
+            _ => Some(c),
+        })
+        .collect();
+    ret.dedup();
+    ret.into_iter().collect()
+}
+
+/// A query over the entries in a `Dhatupatha`.
+///
+/// All conditions are optional, and an entry matches the query only if it matches every condition
+/// that has been set.
+///
+/// # Example
+///
+/// ```
+/// # use vidyut_prakriya::Error;
+/// # use vidyut_prakriya::args::Gana;
+/// # use vidyut_prakriya::dhatupatha::{Dhatupatha, Padi, Query};
+/// let d = Dhatupatha::from_text(
+///     "code\tdhatu\tartha\n\
+///      01.0001\tBU\tsattAyAm\n\
+///      01.0002\teDa~\\\tvfdDO",
+/// )?;
+/// let query = Query::new().gana(Gana::Bhvadi).padi(Padi::Atmanepadi);
+/// let codes: Vec<_> = d.search(&query).map(|e| e.code().as_str()).collect();
+/// assert_eq!(codes, vec!["01.0002"]);
+/// # Ok::<(), Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Query {
+    artha: Option<String>,
+    tags: Vec<Tag>,
+    gana: Option<Gana>,
+    antargana: Option<Antargana>,
+    it_status: Option<ItStatus>,
+    padi: Option<Padi>,
+}
+
+impl Query {
+    /// Creates an empty query, which matches all entries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches entries whose artha contains `text`.
+    ///
+    /// The match is loose: we ignore spaces, vowel length, aspiration, doubled sounds, and the
+    /// distinctions among sibilants and among nasals, so `"satayam"` matches `"sattAyAm"`.
+    pub fn artha(mut self, text: impl AsRef<str>) -> Self {
+        self.artha = Some(normalize_artha(text.as_ref()));
+        self
+    }
+
+    /// Matches entries whose dhatu has `tag` after it-samjna. Calling this function more than
+    /// once matches entries that have all of the given tags.
+    pub fn tag(mut self, tag: Tag) -> Self {
+        self.tags.push(tag);
+        self
+    }
+
+    /// Matches entries in the given gana.
+    pub fn gana(mut self, gana: Gana) -> Self {
+        self.gana = Some(gana);
+        self
+    }
+
+    /// Matches entries in the given antargana.
+    pub fn antargana(mut self, antargana: Antargana) -> Self {
+        self.antargana = Some(antargana);
+        self
+    }
+
+    /// Matches entries with the given it-Agama behavior.
+    pub fn it_status(mut self, it_status: ItStatus) -> Self {
+        self.it_status = Some(it_status);
+        self
+    }
+
+    /// Matches entries that take the given padas.
+    pub fn padi(mut self, padi: Padi) -> Self {
+        self.padi = Some(padi);
+        self
+    }
+
+    fn matches(&self, entry: &Entry) -> bool {
+        let dhatu = &entry.dhatu;
+        if let Some(gana) = self.gana {
+            if dhatu.gana() != Some(gana) {
+                return false;
+            }
+        }
+        if let Some(antargana) = self.antargana {
+            if dhatu.antargana() != Some(antargana) {
+                return false;
+            }
+        }
+        if let Some(artha) = &self.artha {
+            if !normalize_artha(&entry.artha).contains(artha.as_str()) {
+                return false;
+            }
+        }
+
+        if self.tags.is_empty() && self.it_status.is_none() && self.padi.is_none() {
+            return true;
+        }
+        // Run it-samjna only if we need to.
+        let t = match entry.create_term() {
+            Some(t) => t,
+            None => return false,
+        };
+        self.tags.iter().all(|tag| t.has_tag(*tag))
+            && self.it_status.map_or(true, |x| it_status(&t) == x)
+            && self.padi.map_or(true, |x| padi(&t) == x)
+    }
+}
+
 /// An interface to the Dhatupatha used on <ashtadhyayi.com>.
 ///
 /// Different traditional texts might use different dhatupathas. This struct manages the data for
@@ -67,7 +338,9 @@ impl Entry {
 ///
 /// The specific dhatupatha we use matters: for certain dhatus, we can determine their metadata
 /// only if we know exactly where they are located. (For an example, see our implementation of the
-/// private `maybe_find_antargana` function.)
+/// private `maybe_find_antargana` function.) For this reason, every entry keeps its code from the
+/// ashtadhyayi.com dhatupatha even if we select only the dhatus from one source with
+/// [`Dhatupatha::edition`].
 pub struct Dhatupatha(Vec<Entry>);
 
 /// Creates a dhatu with the given metadata. This function is meant for testing or for other ad-hoc
@@ -98,9 +371,13 @@ pub fn create_dhatu(upadesha: impl AsRef<str>, gana: Gana, number: u16) -> Resul
 impl Dhatupatha {
     /// Loads a dhatupatha from the provided TSV.
     ///
-    /// This function expects a TSV with headers and at least two columns. The first column is a
-    /// short numeric code associated with the dhatu (e.g. `"01.0001"`), and the second column is
-    /// the upadesha (e.g. `"BU"`).
+    /// This function expects a TSV with headers and at least three columns. The first column is a
+    /// short numeric code associated with the dhatu (e.g. `"01.0001"`), the second column is
+    /// the upadesha (e.g. `"BU"`), and the third column is the artha (e.g. `"sattAyAm"`).
+    ///
+    /// An optional fourth column lists the sources that attest the dhatu as a comma-separated
+    /// list of [`Source`] codes (e.g. `"kaumudi,madhaviya"`). If this column is missing or
+    /// contains `"-"`, the entry has no sources.
     ///
     /// # Example
     ///
@@ -151,13 +428,14 @@ impl Dhatupatha {
                 Some(x) => x,
                 None => return Err(Error::InvalidFile),
             };
+            let sources = fields.next().unwrap_or("");
 
             // If the upadesha is missing, this is a ganasutra -- skip.
             if upadesha == "-" {
                 continue;
             }
 
-            let entry = Entry::parse(code, upadesha, artha)?;
+            let entry = Entry::parse(code, upadesha, artha, sources)?;
             dhatus.push(entry);
         }
 
@@ -165,6 +443,48 @@ impl Dhatupatha {
         Ok(Self(dhatus))
     }
 
+    /// Returns an iterator over all entries in this Dhatupatha.
+    pub fn iter(&self) -> std::slice::Iter<'_, Entry> {
+        self.0.iter()
+    }
+
+    /// Returns only the entries attested in `source`.
+    ///
+    /// Returns an error if no entry lists any sources, e.g. if the data has no `sources`
+    /// column. (The bundled `data/dhatupatha.tsv` does not have this column yet.)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::Error;
+    /// # use vidyut_prakriya::dhatupatha::{Dhatupatha, Source};
+    /// let d = Dhatupatha::from_text(
+    ///     "code\tdhatu\tartha\tsources\n\
+    ///      01.0001\tBU\tsattAyAm\tkaumudi,madhaviya\n\
+    ///      01.0002\teDa~\\\tvfdDO\tmadhaviya",
+    /// )?;
+    /// let sk = d.edition(Source::Siddhantakaumudi)?;
+    /// assert!(sk.get("01.0001").is_some());
+    /// assert!(sk.get("01.0002").is_none());
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn edition(self, source: Source) -> Result<Self> {
+        if self.0.iter().all(|e| e.sources().is_empty()) {
+            return Err(Error::missing_required_field("sources"));
+        }
+        Ok(Self(
+            self.0
+                .into_iter()
+                .filter(|e| e.is_attested_in(source))
+                .collect(),
+        ))
+    }
+
+    /// Returns an iterator over all entries that match `query`.
+    pub fn search<'a>(&'a self, query: &'a Query) -> impl Iterator<Item = &'a Entry> + 'a {
+        self.0.iter().filter(move |e| query.matches(e))
+    }
+
     /// Gets the dhatu with the given code.
     pub fn get(&self, code: &str) -> Option<&Dhatu> {
         match self.0.binary_search_by_key(&code, |d| &d.code) {
@@ -232,6 +552,120 @@ mod tests {
         assert!(dhatu.sanadi().is_empty());
     }
 
+    #[test]
+    fn from_text_with_sources() {
+        let d = Dhatupatha::from_text(
+            "code\tdhatu\tartha\tsources\n\
+             01.0001\tBU\tsattAyAm\tkaumudi,kshiratarangini\n\
+             01.0002\teDa~\\\tvfdDO\t-",
+        )
+        .unwrap();
+        let entries: Vec<_> = d.iter().collect();
+        assert_eq!(
+            entries[0].sources(),
+            &[Source::Siddhantakaumudi, Source::Kshiratarangini]
+        );
+        assert!(entries[1].sources().is_empty());
+
+        let kt = d.edition(Source::Kshiratarangini).unwrap();
+        assert_eq!(kt.iter().count(), 1);
+    }
+
+    #[test]
+    fn edition_without_sources() {
+        let d = Dhatupatha::from_text("code\tdhatu\tartha\n01.0001\tBU\tsattAyAm").unwrap();
+        assert!(d.edition(Source::Siddhantakaumudi).is_err());
+    }
+
+    #[test]
+    #[ignore = "data/dhatupatha.tsv has no sources column yet; see data/README.md"]
+    fn edition_of_bundled_dhatupatha() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/data/dhatupatha.tsv");
+        let num_all = Dhatupatha::from_path(path).unwrap().iter().count();
+        let num_kt = Dhatupatha::from_path(path)
+            .unwrap()
+            .edition(Source::Kshiratarangini)
+            .unwrap()
+            .iter()
+            .count();
+        assert!(num_kt > 0);
+        assert!(num_kt < num_all);
+    }
+
+    #[test]
+    fn from_text_with_unknown_source() {
+        assert!(Dhatupatha::from_text("code\tdhatu\tartha\n01.0001\tBU\tsattAyAm\tfoo").is_err());
+    }
+
+    #[test]
+    fn search_by_it_status() {
+        let d = Dhatupatha::from_text(
+            "code\tdhatu\tartha\n\
+             01.0001\tBU\tsattAyAm\n\
+             01.0461\tgupU~\trakzaRe\n\
+             01.1081\tsvf\tSabdopatApayoH\n\
+             02.0001\ta\\da~\tBakzaRe\n\
+             05.0010\tDUY\tkampane",
+        )
+        .unwrap();
+        let codes = |q: Query| -> Vec<String> { d.search(&q).map(|e| e.code().clone()).collect() };
+
+        assert_eq!(
+            codes(Query::new().it_status(ItStatus::Set)),
+            vec!["01.0001"]
+        );
+        assert_eq!(
+            codes(Query::new().it_status(ItStatus::Anit)),
+            vec!["02.0001"]
+        );
+        assert_eq!(
+            codes(Query::new().it_status(ItStatus::Vet)),
+            vec!["01.0461", "01.1081", "05.0010"]
+        );
+    }
+
+    #[test]
+    fn search() {
+        let d = Dhatupatha::from_text(
+            "code\tdhatu\tartha\n\
+             01.0001\tBU\tsattAyAm\n\
+             01.0002\teDa~\\\tvfdDO\n\
+             01.0006\tQOkf~\\\tgatO\n\
+             01.1065\tqupa\\ca~^z\tpAke\n\
+             02.0001\ta\\da~\tBakzaRe\n\
+             06.0001\ttu\\da~^\tvyaTane",
+        )
+        .unwrap();
+        let codes = |q: Query| -> Vec<String> { d.search(&q).map(|e| e.code().clone()).collect() };
+
+        assert_eq!(codes(Query::new().artha("satayam")), vec!["01.0001"]);
+        assert_eq!(codes(Query::new().gana(Gana::Adadi)), vec!["02.0001"]);
+        assert_eq!(
+            codes(Query::new().padi(Padi::Atmanepadi)),
+            vec!["01.0002", "01.0006"]
+        );
+        assert_eq!(
+            codes(Query::new().padi(Padi::Ubhayapadi)),
+            vec!["01.1065", "06.0001"]
+        );
+        assert_eq!(
+            codes(Query::new().it_status(ItStatus::Anit)),
+            vec!["01.1065", "02.0001", "06.0001"]
+        );
+        assert_eq!(
+            codes(Query::new().tag(Tag::svaritet).gana(Gana::Tudadi)),
+            vec!["06.0001"]
+        );
+        assert_eq!(
+            codes(Query::new().tag(Tag::wvit).tag(Tag::zit)),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            codes(Query::new().tag(Tag::qvit).tag(Tag::zit)),
+            vec!["01.1065"]
+        );
+    }
+
     #[test]
     fn create_dhatu_with_adhii() {
         let i_n = create_dhatu("i\\N", Gana::Adadi, 41).unwrap();
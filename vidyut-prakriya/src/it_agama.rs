@@ -45,16 +45,27 @@ lazy_static! {
     static ref UK: Set = s("uk");
 }
 
-fn is_hacky_eka_ac(p: &Prakriya, i: usize) -> bool {
+fn is_hacky_eka_ac_term(t: &Term) -> bool {
     // HACK to have ekac apply for am-Agama.
+    t.is_ekac() || t.text.contains("fa")
+}
+
+fn is_hacky_eka_ac(p: &Prakriya, i: usize) -> bool {
     // Ignore yan-luk per SK 2651.
-    p.has(i, |t| t.is_ekac() || t.text.contains("fa")) && !p.has(i + 1, |t| t.is_yan_luk())
+    p.has(i, is_hacky_eka_ac_term) && !p.has(i + 1, |t| t.is_yan_luk())
+}
+
+/// Returns whether the given dhatu is aniT by 7.2.10.
+///
+/// This checks only the dhatu itself, so callers must check for yan-luk separately.
+pub(crate) fn is_ekac_anudatta(dhatu: &Term) -> bool {
+    dhatu.has_tag(T::Anudatta) && is_hacky_eka_ac_term(dhatu)
 }
 
 /// Returns whether the given term is vet by 7.2.44.
 ///
 /// We wrap this condition in its own function because other rules need to check it as well.
-fn is_svarati_suti(anga: &Term) -> bool {
+pub(crate) fn is_svarati_suti(anga: &Term) -> bool {
     // > vakṣyati svaratisūtisūyatidhūñūdito vā 7.2.44। vidhūtaḥ। vidhūtavān। guhū gūḍhaḥ। gūḍhavān।
     // > udito vā vṛdhu vṛddhaḥ।
     // -- Kashikavrtti on 7.2.15.
@@ -210,6 +221,7 @@ fn try_dirgha_for_it_agama(p: &mut Prakriya, i_it: usize) -> Option<()> {
     Some(())
 }
 
+/// Skipped: 7.2.64 ("baBUTa ...") except for vavarTa, 7.2.69 ("saniMsasanivAMsam")
 fn run_valadau_ardhadhatuke_before_attva_for_term(ip: &mut ItPrakriya) -> Option<()> {
     let anga = ip.anga();
     let n = ip.next();
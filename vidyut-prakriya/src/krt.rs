@@ -5,6 +5,8 @@ mod basic;
 mod unadipatha;
 mod utils;
 
+pub(crate) use basic::is_upapada_krt;
+
 pub fn run(p: &mut Prakriya, args: &Krdanta) -> bool {
     // First, check if the pratyaya is an unAdi-pratyaya.
     let mut added = unadipatha::run(p, args.krt());
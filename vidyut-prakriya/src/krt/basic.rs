@@ -509,6 +509,21 @@ fn is_nandi_grahi_pacadi(kp: &KrtPrakriya) -> bool {
     dhatu.has_text_in(NAND_ADI) || dhatu.has_text_in(PAC_ADI)
 }
 
+/// The pratyayas handled by `try_add_upapada_krt`, most of which are available only after an
+/// upapada. `try_add_upapada_krt` skips the upapada rules for any other pratyaya.
+const UPAPADA_KRTS: &[BaseKrt] = {
+    use BaseKrt::*;
+    &[
+        aR, ka, ac, wa, wak, in_, KaS, Kac, qa, Rini, Kyun, KizRuc, KukaY, kvin, kaY, viw, kap,
+        kvip, ini, kvanip,
+    ]
+};
+
+/// Returns whether `krt` is one of the pratyayas handled by `try_add_upapada_krt`.
+pub(crate) fn is_upapada_krt(krt: BaseKrt) -> bool {
+    UPAPADA_KRTS.contains(&krt)
+}
+
 fn try_add_upapada_krt(p: &mut Prakriya, krt: BaseKrt) -> Option<bool> {
     use BaseKrt::*;
 
@@ -525,6 +540,9 @@ fn try_add_upapada_krt(p: &mut Prakriya, krt: BaseKrt) -> Option<bool> {
     // records whether or not any of these rules were applied.
     let mut kp = KrtPrakriya::new(p, krt);
     try_add_various_pratyayas(&mut kp);
+    if !is_upapada_krt(krt) {
+        return Some(kp.has_krt);
+    }
 
     let i_dhatu = kp.p.find_first_where(|t| t.is_dhatu())?;
     let dhatu = kp.dhatu();
@@ -1291,6 +1309,27 @@ fn try_add_krt(p: &mut Prakriya, krt: BaseKrt) -> Option<bool> {
             kp.try_add("3.3.10", krt);
         }
 
+        K::se
+        | K::sen
+        | K::ase
+        | K::asen
+        | K::kse
+        | K::kasen
+        | K::aDyE
+        | K::aDyEn
+        | K::kaDyE
+        | K::kaDyEn
+        | K::SaDyE
+        | K::SaDyEn
+        | K::tavE
+        | K::taveN
+        | K::taven
+            if kp.p.is_chandasi() =>
+        {
+            // jIvase, pibaDyE, pAtavE
+            kp.try_add("3.4.9", krt);
+        }
+
         K::ktri => {
             if dhatu.has_tag(T::qvit) {
                 if kp.try_add("3.3.88", krt) {
@@ -2,8 +2,14 @@
 #![deny(missing_docs)]
 #![deny(clippy::unwrap_used)]
 
-pub use crate::core::{Error, Prakriya, Rule, RuleChoice, Step};
+pub use crate::analyzer::{Analysis, AnalysisArgs, Analyzer, AnalyzerBuilder};
+pub use crate::core::errors::{AbortReason, Diagnostic};
+pub use crate::core::{
+    ChoiceDivergence, Error, Prakriya, PrakriyaDiff, Rule, RuleChoice, Step, StepDiff, StepTerm,
+    Svara, SvaraNotation, TermDiff,
+};
 pub use crate::dhatupatha::Dhatupatha;
+pub use crate::paradigm::{KrdantaEntry, SubantaParadigm, TinantaParadigm, TinantaTable};
 pub use crate::vyakarana::{Vyakarana, VyakaranaBuilder};
 
 // Public modules.
@@ -23,9 +29,11 @@ pub mod private {
 
 // Data structures and utilities
 mod core;
+mod paradigm;
 mod sounds;
 
 // Other texts.
+mod analyzer;
 mod ashtadhyayi;
 mod ganapatha;
 mod linganushasanam;
@@ -2,6 +2,7 @@
 Implements rules from the pāṇiṇīyaliṅgānuśāśanam, which assigns lingas to various terms.
 */
 
+use crate::args::Linga;
 use crate::core::Tag as T;
 use crate::core::{Prakriya, Rule};
 
@@ -74,13 +75,27 @@ const DVARA_ADI: &[&str] = &[
 struct LingaPrakriya<'a> {
     p: &'a mut Prakriya,
     done: bool,
+    /// The first rule that decided the linga of this term, along with the lingas it allows.
+    decision: Option<(Rule, Vec<Linga>)>,
 }
 
 impl<'a> LingaPrakriya<'a> {
     fn new(p: &'a mut Prakriya) -> Self {
-        LingaPrakriya { p, done: false }
+        LingaPrakriya {
+            p,
+            done: false,
+            decision: None,
+        }
     }
+
+    fn decide(&mut self, rule: Rule, lingas: &[Linga]) {
+        if self.decision.is_none() {
+            self.decision = Some((rule, lingas.to_vec()));
+        }
+    }
+
     fn mark_pum(&mut self, rule: Rule) {
+        self.decide(rule, &[Linga::Pum]);
         if !self.done {
             self.p.add_tag(T::Pum);
             self.p.step(rule);
@@ -89,6 +104,7 @@ impl<'a> LingaPrakriya<'a> {
     }
 
     fn mark_stri(&mut self, rule: Rule) {
+        self.decide(rule, &[Linga::Stri]);
         if !self.done {
             self.p.add_tag(T::Stri);
             self.p.step(rule);
@@ -97,6 +113,7 @@ impl<'a> LingaPrakriya<'a> {
     }
 
     fn mark_napumsaka(&mut self, rule: Rule) {
+        self.decide(rule, &[Linga::Napumsaka]);
         if !self.done {
             self.p.add_tag(T::Napumsaka);
             self.p.step(rule);
@@ -104,23 +121,49 @@ impl<'a> LingaPrakriya<'a> {
         self.done = true;
     }
 
-    fn mark_pum_napumsaka(&mut self, _rule: Rule) {}
+    fn mark_pum_napumsaka(&mut self, rule: Rule) {
+        self.decide(rule, &[Linga::Pum, Linga::Napumsaka]);
+        // Allow either linga, but block any later rule from choosing just one.
+        self.done = true;
+    }
 
-    fn mark_stri_napumsaka(&mut self, _rule: Rule) {}
+    fn mark_stri_napumsaka(&mut self, rule: Rule) {
+        self.decide(rule, &[Linga::Stri, Linga::Napumsaka]);
+        // Allow either linga, but block any later rule from choosing just one.
+        self.done = true;
+    }
 
-    fn mark_stri_pum(&mut self, _rule: Rule) {}
+    fn mark_stri_pum(&mut self, rule: Rule) {
+        self.decide(rule, &[Linga::Stri, Linga::Pum]);
+        // Allow either linga, but block any later rule from choosing just one.
+        self.done = true;
+    }
 }
 
 /// Runs the linganushasana rules over the given prakriya.
 pub fn run(p: &mut Prakriya) -> Option<()> {
-    use Rule::Linganushasana as L;
-
     if p.has_tag(T::Stri) {
         return None;
     }
 
     let mut lp = LingaPrakriya::new(p);
-    let i_last = lp.p.terms().len() - 1;
+    run_rules(&mut lp)
+}
+
+/// Returns the lingas that the linganushasana allows for the given prakriya, along with the rule
+/// that decides them.
+///
+/// Unlike `run`, this function also inspects terms that are already feminine.
+pub fn decide_lingas(p: &mut Prakriya) -> Option<(Rule, Vec<Linga>)> {
+    let mut lp = LingaPrakriya::new(p);
+    run_rules(&mut lp);
+    lp.decision
+}
+
+fn run_rules(lp: &mut LingaPrakriya) -> Option<()> {
+    use Rule::Linganushasana as L;
+
+    let i_last = lp.p.terms().len().checked_sub(1)?;
 
     // strI (2 - 34)
     // =============
@@ -0,0 +1,211 @@
+//! Structured tables of inflected forms.
+//!
+//! A paradigm collects all of the forms that a dhatu or pratipadika produces for some set of
+//! conditions. Each cell in a paradigm contains every variant for that cell, e.g. both *BavatAt*
+//! and *Bavatu* for the lot-lakara of *BU*.
+use crate::args::{Krt, KrtArtha, Lakara, Purusha, Vacana, Vibhakti};
+use crate::core::Prakriya;
+
+/// The purushas in a `TinantaTable`, in row order.
+pub(crate) const PURUSHAS: [Purusha; 3] = [Purusha::Prathama, Purusha::Madhyama, Purusha::Uttama];
+
+/// The vacanas in a `TinantaTable` or `SubantaParadigm`, in column order.
+pub(crate) const VACANAS: [Vacana; 3] = [Vacana::Eka, Vacana::Dvi, Vacana::Bahu];
+
+/// The vibhaktis in a `SubantaParadigm`, in row order.
+pub(crate) const VIBHAKTIS: [Vibhakti; 8] = [
+    Vibhakti::Prathama,
+    Vibhakti::Dvitiya,
+    Vibhakti::Trtiya,
+    Vibhakti::Caturthi,
+    Vibhakti::Panchami,
+    Vibhakti::Sasthi,
+    Vibhakti::Saptami,
+    Vibhakti::Sambodhana,
+];
+
+/// Returns the row of `purusha` in `PURUSHAS`.
+fn purusha_index(purusha: Purusha) -> usize {
+    match purusha {
+        Purusha::Prathama => 0,
+        Purusha::Madhyama => 1,
+        Purusha::Uttama => 2,
+    }
+}
+
+/// Returns the column of `vacana` in `VACANAS`.
+fn vacana_index(vacana: Vacana) -> usize {
+    match vacana {
+        Vacana::Eka => 0,
+        Vacana::Dvi => 1,
+        Vacana::Bahu => 2,
+    }
+}
+
+/// Returns the row of `vibhakti` in `VIBHAKTIS`.
+fn vibhakti_index(vibhakti: Vibhakti) -> usize {
+    match vibhakti {
+        Vibhakti::Prathama => 0,
+        Vibhakti::Dvitiya => 1,
+        Vibhakti::Trtiya => 2,
+        Vibhakti::Caturthi => 3,
+        Vibhakti::Panchami => 4,
+        Vibhakti::Sasthi => 5,
+        Vibhakti::Saptami => 6,
+        Vibhakti::Sambodhana => 7,
+    }
+}
+
+/// A 3x3 table of tinantas for a single lakara.
+///
+/// Rows are purushas (prathama, madhyama, uttama), and columns are vacanas (eka, dvi, bahu).
+#[derive(Debug)]
+pub struct TinantaTable {
+    lakara: Lakara,
+    cells: Vec<Vec<Prakriya>>,
+}
+
+impl TinantaTable {
+    /// Creates a table from `cells`, which are in row-major order.
+    pub(crate) fn new(lakara: Lakara, cells: Vec<Vec<Prakriya>>) -> Self {
+        debug_assert_eq!(cells.len(), PURUSHAS.len() * VACANAS.len());
+        Self { lakara, cells }
+    }
+
+    /// The lakara used in this table.
+    pub fn lakara(&self) -> Lakara {
+        self.lakara
+    }
+
+    /// Returns all prakriyas for the given purusha and vacana.
+    pub fn get(&self, purusha: Purusha, vacana: Vacana) -> &[Prakriya] {
+        &self.cells[purusha_index(purusha) * VACANAS.len() + vacana_index(vacana)]
+    }
+
+    /// Returns whether every cell in this table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.cells.iter().all(|c| c.is_empty())
+    }
+}
+
+/// All tinantas for a dhatu in some prayoga, grouped by lakara.
+///
+/// This struct is created by `Vyakarana::derive_tinanta_paradigm`.
+#[derive(Debug)]
+pub struct TinantaParadigm {
+    tables: Vec<TinantaTable>,
+}
+
+impl TinantaParadigm {
+    pub(crate) fn new(tables: Vec<TinantaTable>) -> Self {
+        Self { tables }
+    }
+
+    /// Returns the tables in this paradigm, in the order defined by `Lakara::iter`.
+    pub fn tables(&self) -> &[TinantaTable] {
+        &self.tables
+    }
+
+    /// Returns the table for the given lakara, if one exists.
+    pub fn get(&self, lakara: Lakara) -> Option<&TinantaTable> {
+        self.tables.iter().find(|t| t.lakara == lakara)
+    }
+}
+
+/// An 8x3 table of subantas for a pratipadika in some linga.
+///
+/// Rows are vibhaktis (prathama through saptami, then sambodhana), and columns are vacanas (eka,
+/// dvi, bahu).
+///
+/// This struct is created by `Vyakarana::derive_subanta_paradigm`.
+#[derive(Debug)]
+pub struct SubantaParadigm {
+    cells: Vec<Vec<Prakriya>>,
+}
+
+impl SubantaParadigm {
+    /// Creates a table from `cells`, which are in row-major order.
+    pub(crate) fn new(cells: Vec<Vec<Prakriya>>) -> Self {
+        debug_assert_eq!(cells.len(), VIBHAKTIS.len() * VACANAS.len());
+        Self { cells }
+    }
+
+    /// Returns all prakriyas for the given vibhakti and vacana.
+    pub fn get(&self, vibhakti: Vibhakti, vacana: Vacana) -> &[Prakriya] {
+        &self.cells[vibhakti_index(vibhakti) * VACANAS.len() + vacana_index(vacana)]
+    }
+
+    /// Returns whether every cell in this table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.cells.iter().all(|c| c.is_empty())
+    }
+}
+
+/// A krt-pratyaya that a dhatu can take, along with the krdantas it produces.
+///
+/// Entries are created by `Vyakarana::derive_all_krdantas`.
+#[derive(Debug)]
+pub struct KrdantaEntry {
+    krt: Krt,
+    artha: Option<KrtArtha>,
+    requires_upapada: bool,
+    prakriyas: Vec<Prakriya>,
+}
+
+impl KrdantaEntry {
+    pub(crate) fn new(
+        krt: Krt,
+        artha: Option<KrtArtha>,
+        requires_upapada: bool,
+        prakriyas: Vec<Prakriya>,
+    ) -> Self {
+        Self {
+            krt,
+            artha,
+            requires_upapada,
+            prakriyas,
+        }
+    }
+
+    /// The krt-pratyaya for this entry.
+    pub fn krt(&self) -> Krt {
+        self.krt
+    }
+
+    /// The meaning of the rule that added `krt`, if that rule has a specific meaning.
+    pub fn artha(&self) -> Option<KrtArtha> {
+        self.artha
+    }
+
+    /// Whether `krt` is a pratyaya that the grammar usually adds after an upapada and that
+    /// produced nothing for this dhatu without one. If so, `prakriyas` is empty, and callers can
+    /// try `Krdanta::builder().upapada(...)` to derive these forms.
+    ///
+    /// This flag does not check whether some upapada actually allows `krt` after this dhatu.
+    pub fn requires_upapada(&self) -> bool {
+        self.requires_upapada
+    }
+
+    /// All prakriyas for this entry.
+    pub fn prakriyas(&self) -> &[Prakriya] {
+        &self.prakriyas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_helpers_match_constants() {
+        for (i, purusha) in PURUSHAS.iter().enumerate() {
+            assert_eq!(purusha_index(*purusha), i);
+        }
+        for (i, vacana) in VACANAS.iter().enumerate() {
+            assert_eq!(vacana_index(*vacana), i);
+        }
+        for (i, vibhakti) in VIBHAKTIS.iter().enumerate() {
+            assert_eq!(vibhakti_index(*vibhakti), i);
+        }
+    }
+}
@@ -1,13 +1,197 @@
+/*!
+Implements the rules of the Phit-sutras, which assign svaras to pratipadikas that are not derived
+through the rules of the Ashtadhyayi.
+
+The Phit-sutras have four padas:
+
+1. rules that make the last vowel *udAtta*.
+2. rules that make the first vowel *udAtta*.
+3. rules that make the second or penultimate vowel *udAtta*.
+4. rules for nipatas, upasargas, and *svarita* stems.
+
+This module is a partial implementation. We implement only the following sutras:
+
+- pada 1: 1.1 - 1.3, 1.8 - 1.10, 1.16, and 1.18.
+- pada 2: 2.2 - 2.4.
+- pada 3: 3.2, 3.4, and 3.5.
+- pada 4: 4.12 - 4.14, 4.16, and 4.19 - 4.22.
+
+All other sutras are not yet implemented. Some of these (e.g. 1.11 - 1.15, 1.17, and 4.15) have
+conditions that we can't yet check, and 4.15 also requires two *udAtta* vowels on one term.
+
+Many Phit-sutras condition on a stem's meaning (e.g. "words that mean 'house'") or on whether a
+stem is used only in some linga (*strIvizaya*, *napvizaya*). Since we have no lexicon for these
+conditions, each such rule applies only to the stems in an explicit list.
+*/
 use crate::core::term::Svara;
-use crate::core::{Prakriya, Rule};
+use crate::core::{Prakriya, Rule, Tag as T, Term};
+
+/// Words that mean pAwalA, apAlaNka, ambA, or sAgara.
+const PATALA_ADI: &[&str] = &["pAwalA", "apAlaNka", "ambA", "sAgara", "samudra"];
+
+/// Words that mean "house."
+const GEHA_ADI: &[&str] = &["geha", "gfha", "veSman", "sadman", "Sarman"];
+
+/// The ghftAdi-gana.
+const GHRTA_ADI: &[&str] = &["Gfta", "kzIra", "kfzRa", "arRa", "aNga", "ahi"];
+
+/// Stems that are used only in the feminine (*strIvizaya*) and that end in a short vowel.
+const STRI_VISHAYA: &[&str] = &["BUmi", "ozaDi", "nABi"];
+
+/// Stems that are used only in the neuter (*napvizaya*) and that don't end in "is".
+const NAP_VISHAYA: &[&str] = &["vana", "mUla", "Pala", "udaka"];
+
+/// Names of grasses and grains.
+const TRNA_DHANYA: &[&str] = &["kuSa", "kASa", "yava", "tila", "mASa", "mudga"];
+
+/// The Simsumara-Adi list from Phit 3.4.
+const SHIMSHUMARA_ADI: &[&str] = &["SiMSumAra", "udumbara", "balIvarda", "uzwrAra", "purUravas"];
+
+/// The sANkASya-Adi list from Phit 3.5.
+const SANKASHYA_ADI: &[&str] = &["sANkASya", "kAmpilya", "nAsikya", "dArvAGAwa"];
+
+/// Nipatas that are *udAtta* on their last vowel.
+const EVA_ADI: &[&str] = &["eva", "evam", "nUnam", "saha", "satrA", "sadA"];
+
+/// Nipatas that are entirely *anudAtta*.
+const CA_ADI: &[&str] = &["ca", "vA", "ha", "aha", "sma", "u", "cit", "svit", "iva"];
+
+/// Upasargas, which are *udAtta* on their first vowel (except for aBi).
+const UPASARGA: &[&str] = &[
+    "pra", "parA", "apa", "sam", "anu", "ava", "nis", "nir", "dus", "dur", "vi", "A", "ni", "aDi",
+    "api", "ati", "su", "ud", "aBi", "prati", "pari", "upa",
+];
+
+fn set_adi_udatta(t: &mut Term) {
+    t.set_svara(Svara::Udatta(0));
+}
+
+fn set_dvitiya_udatta(t: &mut Term) {
+    t.set_svara(Svara::Udatta(1));
+}
+
+fn set_upadha_udatta(t: &mut Term) {
+    let n = t.num_vowels();
+    t.set_svara(Svara::Udatta(n.saturating_sub(2)));
+}
+
+fn set_antya_udatta(t: &mut Term) {
+    let n = t.num_vowels();
+    t.set_svara(Svara::Udatta(n.saturating_sub(1)));
+}
+
+fn set_antya_svarita(t: &mut Term) {
+    let n = t.num_vowels();
+    t.set_svara(Svara::Svarita(n.saturating_sub(1)));
+}
+
+fn set_anudatta(t: &mut Term) {
+    t.set_svara(Svara::Anudatta);
+}
+
+/// Runs the rules for avyayas, which mostly come from the fourth pada.
+fn run_for_avyaya(p: &mut Prakriya, i: usize) -> Option<()> {
+    use Rule::Phit as P;
+
+    let t = p.get(i)?;
+    if t.has_text("aBi") {
+        // aBi/
+        p.run_at(P("4.13"), i, set_antya_udatta);
+    } else if t.has_text_in(UPASARGA) {
+        // pra/, a/pa, u/pa
+        p.run_at(P("4.13"), i, set_adi_udatta);
+    } else if t.has_text_in(EVA_ADI) {
+        // eva/, evam/
+        p.run_at(P("4.14"), i, set_antya_udatta);
+    } else if t.has_text_in(CA_ADI) {
+        // ca, vA
+        p.run_at(P("4.16"), i, set_anudatta);
+    } else if t.has_text_in(&["tvat", "tva", "sama", "sima"]) {
+        if t.has_text("sima") && p.is_chandasi() {
+            // TODO: restrict to the Atharvaveda.
+            p.run_at(P("4.22"), i, set_antya_udatta);
+        } else {
+            // tva, sama
+            p.run_at(P("4.21"), i, set_anudatta);
+        }
+    } else {
+        // TODO: 4.15 (vAcAdInAm uBAv udAttO) requires two udAttas on one term.
+        p.run_at(P("4.12"), i, set_adi_udatta);
+    }
+
+    Some(())
+}
 
 fn run_at(p: &mut Prakriya, i: usize) -> Option<()> {
     use Rule::Phit as P;
-    use Svara::*;
 
-    let num_vowels = p.get(i)?.num_vowels();
+    let t = p.get(i)?;
+    if t.is_avyaya() {
+        return run_for_avyaya(p, i);
+    }
+
+    let is_stri = p.has_tag(T::Stri);
+    let is_napumsaka = p.has_tag(T::Napumsaka);
+    let num_vowels = t.num_vowels();
 
-    p.run_at(P("1.1"), i, |t| t.set_svara(Udatta(num_vowels - 1)));
+    // Pada 1: antodAtta, with some exceptions.
+    if t.has_text_in(PATALA_ADI) {
+        // pAwalA/, sAgara/
+        p.run_at(P("1.2"), i, set_antya_udatta);
+    } else if t.has_text_in(GEHA_ADI) && !is_stri {
+        // geha/, gfha/
+        p.run_at(P("1.3"), i, set_antya_udatta);
+    } else if t.has_text_in(&["Sukla", "gOra"]) {
+        // Su/kla, gO/ra
+        p.run_at(P("1.8"), i, set_adi_udatta);
+    } else if t.has_text_in(&["aNguzWa", "udaka", "baka", "vaSA"]) && p.is_chandasi() {
+        // udaka/
+        p.run_at(P("1.9"), i, set_antya_udatta);
+    } else if t.has_text("pfzWa") && p.is_chandasi() {
+        // pfzWa/
+        p.run_at(P("1.10"), i, set_antya_udatta);
+    } else if t.has_text_in(GHRTA_ADI) {
+        // Gfta/
+        p.run_at(P("1.16"), i, set_antya_udatta);
+    } else if t.has_text_in(&["bilva", "tizya"]) && !p.is_chandasi() {
+        // bilva^, tizya^
+        p.run_at(P("1.18"), i, set_antya_svarita);
+    }
+    // Pada 4: antasvarita stems.
+    else if t.has_text_in(&[
+        "tilya", "Sikya", "kaSmarya", "DAnya", "kanyA", "rAjanya", "manuzya",
+    ]) {
+        // kanyA^, rAjanya^, manuzya^
+        p.run_at(P("4.19"), i, set_antya_svarita);
+    } else if t.has_text_in(&["bilva", "Bakzya", "vIrya"]) && p.is_chandasi() {
+        // vIrya^
+        p.run_at(P("4.20"), i, set_antya_svarita);
+    }
+    // Pada 3: the second vowel is udAtta.
+    else if t.has_text_in(SHIMSHUMARA_ADI) {
+        // udu/mbara, purU/ravas
+        p.run_at(P("3.4"), i, set_dvitiya_udatta);
+    } else if t.has_text_in(SANKASHYA_ADI) {
+        // sANkA/Sya
+        p.run_at(P("3.5"), i, set_dvitiya_udatta);
+    } else if t.has_text_in(&["pArAvata"]) {
+        // pArA/vata
+        p.run_at(P("3.2"), i, set_upadha_udatta);
+    }
+    // Pada 2: the first vowel is udAtta.
+    else if is_stri && t.has_text_in(STRI_VISHAYA) {
+        // BU/mi, o/zaDi
+        p.run_at(P("2.2"), i, set_adi_udatta);
+    } else if is_napumsaka && t.has_text_in(NAP_VISHAYA) {
+        // va/na, Pa/la
+        p.run_at(P("2.3"), i, set_adi_udatta);
+    } else if t.has_text_in(TRNA_DHANYA) && num_vowels == 2 {
+        // ku/Sa, ya/va
+        p.run_at(P("2.4"), i, set_adi_udatta);
+    } else {
+        // agni/
+        p.run_at(P("1.1"), i, set_antya_udatta);
+    }
 
     Some(())
 }
@@ -15,8 +199,9 @@ fn run_at(p: &mut Prakriya, i: usize) -> Option<()> {
 /// Runs the rules of the Phit-sutras.
 pub fn run(p: &mut Prakriya) {
     for i in 0..p.terms().len() {
-        if p.has(i, |t| t.is_pratipadika() && !t.is_pratyaya())
-            && !p.has(i + 1, |t| t.is_taddhita())
+        if p.has(i, |t| {
+            t.is_pratipadika() && !t.is_pratyaya() && t.num_vowels() > 0
+        }) && !p.has(i + 1, |t| t.is_taddhita())
         {
             run_at(p, i);
         }
@@ -249,7 +249,16 @@ pub fn try_run_for_pada_or_bha(p: &mut Prakriya) -> Option<()> {
                     }
                 });
             } else {
-                p.add_tag_at("1.4.14", i, T::Pada);
+                // A kit-Agama after an overt pratyaya is part of that pratyaya by 1.1.46, so the
+                // pada ends with the Agama (devAs + asu~k).
+                let i_end = if !p.has(i, |t| t.is_lupta())
+                    && p.has(i + 1, |t| t.is_agama() && t.has_tag(T::kit))
+                {
+                    i + 1
+                } else {
+                    i
+                };
+                p.add_tag_at("1.4.14", i_end, T::Pada);
             }
         } else {
             let next = match p.pratyaya(i + 1) {
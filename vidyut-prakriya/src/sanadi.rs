@@ -101,6 +101,8 @@ fn try_add(p: &mut Prakriya, sanadi: &Option<Sanadi>, is_ardhadhatuka: bool) ->
     let mut sp = SanadiPrakriya::new(p, i_last);
     let base = sp.p.get(i_base)?;
     let sup = sp.p.has(i_base + 1, |t| t.is_sup());
+    // Whether we may add `s` because it was requested or because no pratyaya was requested.
+    let allows = |s: Sanadi| sanadi.map_or(true, |x| x == s);
 
     // `Gana` is required so that we can exclude "03.0021 kita~".
     if base.is_dhatu() && base.has_u_in(&["gupa~\\", "tija~\\", "kita~"]) && base.has_gana(Bhvadi) {
@@ -151,9 +153,9 @@ fn try_add(p: &mut Prakriya, sanadi: &Option<Sanadi>, is_ardhadhatuka: bool) ->
                 }
             })
         });
-    } else if sup && base.has_text_in(gana::LOHITA_ADI) || base.has_u("qAc") {
+    } else if allows(kyaz) && (sup && base.has_text_in(gana::LOHITA_ADI) || base.has_u("qAc")) {
         // lohitAyati, lohitAyate, ..
-        sp.add("3.1.13", "kyaz");
+        sp.add("3.1.13", kyaz.as_str());
     } else if sup && base.has_text("kazwa") {
         // kazwAyate, ...
         sp.add("3.1.14", kyaN.as_str());
@@ -199,9 +201,9 @@ fn try_add(p: &mut Prakriya, sanadi: &Option<Sanadi>, is_ardhadhatuka: bool) ->
                 p.set(i_base + 2, |t| t.add_tag(T::Nit));
             }
         });
-    } else if sup && base.has_text_in(&["pucCa", "BARqa", "cIvara"]) {
+    } else if sup && allows(RiN) && base.has_text_in(&["pucCa", "BARqa", "cIvara"]) {
         // utpucCayate, ...
-        sp.add("3.1.20", "RiN");
+        sp.add("3.1.20", RiN.as_str());
     } else if sup
         && base.has_text_in(&[
             "muRqa", "miSra", "SlakzRa", "lavaRa", "vrata", "vastra", "hali", "kali", "kfta",
@@ -318,7 +320,9 @@ fn try_add(p: &mut Prakriya, sanadi: &Option<Sanadi>, is_ardhadhatuka: bool) ->
 }
 
 /// Tries to create a namadhatu using the given arguments.
-pub fn try_create_namadhatu(p: &mut Prakriya, dhatu: &Namadhatu) -> Option<()> {
+///
+/// If `dhatu` requests a specific pratyaya, returns an error if that pratyaya could not be added.
+pub fn try_create_namadhatu(p: &mut Prakriya, dhatu: &Namadhatu) -> Result<()> {
     match dhatu.pratipadika() {
         Pratipadika::Basic(basic) => {
             pratipadika_karya::add_basic(p, &basic);
@@ -331,9 +335,19 @@ pub fn try_create_namadhatu(p: &mut Prakriya, dhatu: &Namadhatu) -> Option<()> {
     su.add_tags(&[T::Pratyaya, T::Sup, T::Vibhakti, T::V1, T::Luk]);
     p.push(su);
 
-    try_add(p, &dhatu.nama_sanadi(), false);
+    try_add(p, dhatu.nama_sanadi(), false);
 
-    Some(())
+    if let Some(sanadi) = dhatu.nama_sanadi() {
+        let added = p
+            .terms()
+            .last()
+            .map_or(false, |t| t.is_pratyaya() && t.has_u(sanadi.as_str()));
+        if !added {
+            return Err(Error::abort(p, AbortReason::SanadiNotAdded(*sanadi)));
+        }
+    }
+
+    Ok(())
 }
 
 pub fn try_add_required(p: &mut Prakriya, is_ardhadhatuka: bool) {
@@ -349,7 +363,7 @@ pub fn try_add_optional(p: &mut Prakriya, sanadi: Sanadi) -> Result<()> {
     if matches!(sanadi, Sanadi::yaN | Sanadi::yaNluk) {
         if let Some(t) = p.terms().last() {
             if !(t.has_u("yaN") && t.is_pratyaya()) {
-                return Err(Error::Abort(p.rule_choices().clone()));
+                return Err(Error::abort(p, AbortReason::SanadiNotAdded(sanadi)));
             }
         }
     }
@@ -137,6 +137,46 @@ fn maybe_do_lut_siddhi(p: &mut Prakriya, i_la: usize, la: Lakara) -> bool {
     }
 }
 
+/// Applies tin-siddhi rules that apply to just leT.
+fn maybe_do_let_only_siddhi(p: &mut Prakriya, i: usize) -> Option<()> {
+    let tin = p.get_if(i, |t| t.has_lakshana("le~w"))?;
+
+    if tin.is_atmanepada() {
+        if tin.has_adi('A') {
+            // mantrayEte, mantrayETe
+            p.run_at("3.4.95", i, op::adi("E"));
+        } else if tin.has_antya('e') {
+            // ISE, gfhyAntE
+            p.optional_run_at("3.4.96", i, op::antya("E"));
+        }
+    } else {
+        if tin.has_tag(T::Uttama) && tin.has_antya('s') {
+            // karavAva, karavAma
+            p.optional_run_at("3.4.98", i, op::antya(""));
+        }
+        let tin = p.get(i)?;
+        if tin.has_antya('i') {
+            // patAt, jozizat
+            p.optional_run_at("3.4.97", i, op::antya(""));
+        }
+    }
+
+    Some(())
+}
+
+/// Adds the aw or Aw Agama to leT.
+fn maybe_add_let_agama(p: &mut Prakriya, i: usize) -> Option<()> {
+    p.get_if(i, |t| t.has_lakshana("le~w"))?;
+
+    // The two Agamas are used in alternation. (patAti, patati)
+    let added = p.optionally("3.4.94", |rule, p| op::insert_agama_at(rule, p, i, "Aw"));
+    if !added {
+        op::insert_agama_at("3.4.94", p, i, "aw");
+    }
+
+    Some(())
+}
+
 /// Applies tin-siddhi rules that apply to just loT.
 fn maybe_do_lot_only_siddhi(p: &mut Prakriya, i: usize) -> Option<()> {
     let tin = p.get_if(i, |t| t.has_lakshana("lo~w"))?;
@@ -308,8 +348,7 @@ fn siddhi(p: &mut Prakriya, la: Lakara) -> Option<()> {
         }
     }
 
-    // TODO: 3.4.94 - 3.4.98
-
+    maybe_do_let_only_siddhi(p, i);
     maybe_do_lot_only_siddhi(p, i);
     // Must occur before 3.4.100 in loT/nit siddhi.
     maybe_replace_jhi_with_jus(p, i, la);
@@ -322,6 +361,9 @@ fn siddhi(p: &mut Prakriya, la: Lakara) -> Option<()> {
         p.set(i, |t| t.remove_tag(T::Sit));
     }
 
+    // Add this Agama last so that the rules above can keep using `i`.
+    maybe_add_let_agama(p, i);
+
     Some(())
 }
 
@@ -615,11 +615,19 @@ pub fn run(p: &mut Prakriya) -> Result<()> {
     } else if tin.has_lakshana("li~w") {
         // See `try_add_am_pratyaya_for_lit`.
     } else if tin.has_tag(T::Sarvadhatuka) {
+        let is_let = tin.has_lakshana("le~w");
         if tin.has_lakshana("lo~w") {
             // Just for vidāṅkurvantu, etc.
             maybe_add_am_pratyaya_for_lot(p);
         }
-        add_sarvadhatuka_vikarana(p);
+        let mut added_sip = false;
+        if is_let {
+            // jozizat, tArizat, mandizat
+            added_sip = p.optional_run("3.1.34", add_vikarana("si~p"));
+        }
+        if !added_sip {
+            add_sarvadhatuka_vikarana(p);
+        }
     }
 
     if let Some(i_vikarana) = p.find_first(T::Vikarana) {
@@ -4,11 +4,23 @@
 //! how words are derived in the system.
 //!
 //! For more detailed control over in constructing `Vyakarana`, see `VyakaranaBuilder`.
-use crate::args::{Dhatu, Krdanta, Pada, Pratipadika, Samasa, Subanta, Taddhitanta, Tinanta};
+use crate::args::{
+    Artha, BaseKrt, Dhatu, DhatuPada, Krdanta, Krt, KrtArtha, Lakara, Linga, Pada, Pratipadika,
+    Prayoga, Samasa, Subanta, Taddhita, TaddhitaArtha, Taddhitanta, Tinanta, Unadi,
+};
 use crate::ashtadhyayi;
+use crate::core::errors::Diagnostic;
 use crate::core::prakriya_stack::PrakriyaStack;
 use crate::core::Prakriya;
+use crate::core::Rule;
+use crate::core::RuleChoice;
 use crate::core::Tag;
+use crate::krt;
+use crate::linganushasanam;
+use crate::paradigm::{
+    KrdantaEntry, SubantaParadigm, TinantaParadigm, TinantaTable, PURUSHAS, VACANAS, VIBHAKTIS,
+};
+use rayon::prelude::*;
 
 /// An interface to the Ashtadhyayi and its related works.
 ///
@@ -48,6 +60,8 @@ pub struct Vyakarana {
     is_chandasi: bool,
     // If set, use svara rules. If unset, output will have no svaras.
     use_svaras: bool,
+    // Optional rules that every derivation must accept or decline.
+    rule_choices: Vec<RuleChoice>,
 }
 
 // TODO: better error handling.
@@ -58,6 +72,7 @@ impl Vyakarana {
             log_steps: true,
             is_chandasi: false,
             use_svaras: false,
+            rule_choices: Vec::new(),
         }
     }
 
@@ -182,24 +197,52 @@ impl Vyakarana {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn derive_tinantas(&self, args: &Tinanta) -> Vec<Prakriya> {
+        self.derive_tinantas_with_diagnostics(args).0
+    }
+
+    /// Like `derive_tinantas`, but also explains why some paths did not produce a result.
+    ///
+    /// If the first return value is empty, the diagnostics will explain why.
+    ///
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::{Diagnostic, Vyakarana};
+    /// # use vidyut_prakriya::Error;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let dhatu = Dhatu::mula("BU", Gana::Bhvadi);
+    /// let args = Tinanta::builder()
+    ///     .dhatu(dhatu)
+    ///     .lakara(Lakara::Lat)
+    ///     .prayoga(Prayoga::Kartari)
+    ///     .purusha(Purusha::Prathama)
+    ///     .vacana(Vacana::Eka)
+    ///     .pada(DhatuPada::Atmane)
+    ///     .build()?;
+    /// let (prakriyas, diagnostics) = v.derive_tinantas_with_diagnostics(&args);
+    /// assert!(prakriyas.is_empty());
+    /// assert!(matches!(diagnostics[0], Diagnostic::PadaMismatch { .. }));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn derive_tinantas_with_diagnostics(
+        &self,
+        args: &Tinanta,
+    ) -> (Vec<Prakriya>, Vec<Diagnostic>) {
         let mut stack = self.create_prakriya_stack();
-        // TODO: handle error properly.
         stack.find_all(|p| ashtadhyayi::derive_tinanta(p, args));
-        let mut prakriyas = stack.prakriyas();
+        let (mut prakriyas, mut diagnostics) = stack.prakriyas_and_diagnostics();
 
         // If the caller specified an explicit pada, keep only the results that match that pada.
         //
         // TODO: to avoid wasting time on deriving words that we'll just throw out, push this
         // further into `derive_tinanta`.
         if let Some(pada) = args.pada() {
-            use crate::args::DhatuPada;
-            prakriyas.retain(|p| match pada {
-                DhatuPada::Parasmai => p.has_tag(pada.as_tag()) && !p.has_tag(Tag::AmAtmanepada),
-                DhatuPada::Atmane => p.has_tag_in(&[pada.as_tag(), Tag::AmAtmanepada]),
-            });
+            retain_pada(pada, &mut prakriyas, &mut diagnostics);
         }
 
-        prakriyas
+        (prakriyas, diagnostics)
     }
 
     /// Returns all possible subanta prakriyas that can be derived with the given initial
@@ -223,9 +266,131 @@ impl Vyakarana {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn derive_subantas(&self, subanta: &Subanta) -> Vec<Prakriya> {
+        self.derive_subantas_with_diagnostics(subanta).0
+    }
+
+    /// Like `derive_subantas`, but also explains why some paths did not produce a result.
+    pub fn derive_subantas_with_diagnostics(
+        &self,
+        subanta: &Subanta,
+    ) -> (Vec<Prakriya>, Vec<Diagnostic>) {
         let mut stack = self.create_prakriya_stack();
         stack.find_all(|p| ashtadhyayi::derive_subanta(p, subanta));
-        stack.prakriyas()
+        stack.prakriyas_and_diagnostics()
+    }
+
+    /// Returns all tinantas for the given dhatu and prayoga, arranged as one 3x3 table per lakara.
+    ///
+    /// If `pada` is set, each cell contains only the results for that pada. The let-lakara is
+    /// included only if `is_chandasi` is set.
+    ///
+    /// All cells for a lakara share the same dhatu, so we derive the dhatu once per lakara and
+    /// reuse it for each of that lakara's cells. We derive lakaras in parallel.
+    ///
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::Vyakarana;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let bhu = Dhatu::mula("BU", Gana::Bhvadi);
+    /// let paradigm = v.derive_tinanta_paradigm(&bhu, Prayoga::Kartari, None);
+    ///
+    /// let lat = paradigm.get(Lakara::Lat).unwrap();
+    /// assert_eq!(lat.get(Purusha::Uttama, Vacana::Bahu)[0].text(), "BavAmaH");
+    /// ```
+    pub fn derive_tinanta_paradigm(
+        &self,
+        dhatu: &Dhatu,
+        prayoga: Prayoga,
+        pada: Option<DhatuPada>,
+    ) -> TinantaParadigm {
+        let lakaras: Vec<Lakara> = Lakara::iter()
+            .copied()
+            .filter(|la| self.is_chandasi || *la != Lakara::Let)
+            .collect();
+
+        let tables = lakaras
+            .par_iter()
+            .map(|lakara| {
+                let mut branches = Vec::new();
+                for purusha in PURUSHAS {
+                    for vacana in VACANAS {
+                        branches.push(move |p: &mut Prakriya| {
+                            ashtadhyayi::finish_tinanta(p, prayoga, *lakara, purusha, vacana)
+                        });
+                    }
+                }
+
+                let cells = self
+                    .create_prakriya_stack()
+                    .find_all_branched(
+                        |p| ashtadhyayi::prepare_tinanta_dhatu(p, dhatu, prayoga, *lakara),
+                        &branches,
+                    )
+                    .into_iter()
+                    .map(|stack| {
+                        let (mut prakriyas, mut diagnostics) = stack.prakriyas_and_diagnostics();
+                        if let Some(pada) = pada {
+                            retain_pada(pada, &mut prakriyas, &mut diagnostics);
+                        }
+                        prakriyas
+                    })
+                    .collect();
+                TinantaTable::new(*lakara, cells)
+            })
+            .collect();
+
+        TinantaParadigm::new(tables)
+    }
+
+    /// Returns all subantas for the given pratipadika and linga, arranged as an 8x3 table.
+    ///
+    /// We derive each vibhakti in parallel. Within a vibhakti, all cells share the same
+    /// pratipadika, so we derive the pratipadika once and reuse it for each cell.
+    ///
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::Vyakarana;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let rama = Pratipadika::basic("rAma");
+    /// let paradigm = v.derive_subanta_paradigm(&rama, Linga::Pum);
+    ///
+    /// assert_eq!(paradigm.get(Vibhakti::Trtiya, Vacana::Eka)[0].text(), "rAmeRa");
+    /// assert_eq!(paradigm.get(Vibhakti::Sambodhana, Vacana::Eka)[0].text(), "rAma");
+    /// ```
+    pub fn derive_subanta_paradigm(
+        &self,
+        pratipadika: &Pratipadika,
+        linga: Linga,
+    ) -> SubantaParadigm {
+        let rows: Vec<Vec<Vec<Prakriya>>> = VIBHAKTIS
+            .par_iter()
+            .map(|vibhakti| {
+                let branches: Vec<_> = VACANAS
+                    .iter()
+                    .map(|vacana| {
+                        move |p: &mut Prakriya| {
+                            ashtadhyayi::finish_subanta(p, linga, *vibhakti, *vacana)
+                        }
+                    })
+                    .collect();
+
+                self.create_prakriya_stack()
+                    .find_all_branched(
+                        |p| ashtadhyayi::prepare_subanta_pratipadika(p, pratipadika, linga),
+                        &branches,
+                    )
+                    .into_iter()
+                    .map(|stack| stack.prakriyas())
+                    .collect()
+            })
+            .collect();
+        SubantaParadigm::new(rows.into_iter().flatten().collect())
     }
 
     /// Returns all possible krdanta prakriyas that can be derived with the given initial
@@ -261,9 +426,35 @@ impl Vyakarana {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn derive_krdantas(&self, krdanta: &Krdanta) -> Vec<Prakriya> {
+        self.derive_krdantas_with_diagnostics(krdanta).0
+    }
+
+    /// Like `derive_krdantas`, but also explains why some paths did not produce a result.
+    ///
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::{AbortReason, Diagnostic, Vyakarana};
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// // `kru` is allowed only after `YiBI\\`, so this derivation is aborted.
+    /// let bhu = Dhatu::mula("BU", Gana::Bhvadi);
+    /// let args = Krdanta::new(bhu, BaseKrt::kru);
+    /// let (prakriyas, diagnostics) = v.derive_krdantas_with_diagnostics(&args);
+    /// assert!(prakriyas.is_empty());
+    /// assert!(matches!(
+    ///     diagnostics[0],
+    ///     Diagnostic::Aborted { reason: AbortReason::KrtNotAdded(_), .. }
+    /// ));
+    /// ```
+    pub fn derive_krdantas_with_diagnostics(
+        &self,
+        krdanta: &Krdanta,
+    ) -> (Vec<Prakriya>, Vec<Diagnostic>) {
         let mut stack = self.create_prakriya_stack();
         stack.find_all(|p| ashtadhyayi::derive_krdanta(p, krdanta));
-        stack.prakriyas()
+        stack.prakriyas_and_diagnostics()
     }
 
     /// Returns all possible taddhitanta prakriyas that can be derived with the given initial
@@ -285,9 +476,163 @@ impl Vyakarana {
     /// # Ok::<(), Error>(())
     /// ```
     pub fn derive_taddhitantas(&self, spec: &Taddhitanta) -> Vec<Prakriya> {
+        self.derive_taddhitantas_with_diagnostics(spec).0
+    }
+
+    /// Like `derive_taddhitantas`, but also explains why some paths did not produce a result.
+    pub fn derive_taddhitantas_with_diagnostics(
+        &self,
+        spec: &Taddhitanta,
+    ) -> (Vec<Prakriya>, Vec<Diagnostic>) {
         let mut stack = self.create_prakriya_stack();
         stack.find_all(|p| ashtadhyayi::derive_taddhitanta(p, spec));
-        stack.prakriyas()
+        stack.prakriyas_and_diagnostics()
+    }
+
+    /// Returns every krdanta that can be derived from `dhatu`, grouped by krt-pratyaya and meaning.
+    ///
+    /// We try every krt-pratyaya from the Ashtadhyayi and every pratyaya from the Unadipatha.
+    /// Some krt-pratyayas (e.g. `KaS` or `kvip`) are mostly available only after an upapada. If
+    /// such a pratyaya produces nothing for `dhatu`, we still return an entry for it with
+    /// `requires_upapada` set and no prakriyas. We don't check which upapadas (if any) allow the
+    /// pratyaya after `dhatu`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::Vyakarana;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let pac = Dhatu::mula("qupa\\ca~^z", Gana::Bhvadi);
+    /// let entries = v.derive_all_krdantas(&pac);
+    /// let tfc = entries
+    ///     .iter()
+    ///     .find(|e| e.krt() == BaseKrt::tfc.into())
+    ///     .unwrap();
+    /// assert!(tfc.prakriyas().iter().any(|p| p.text() == "paktf"));
+    /// ```
+    pub fn derive_all_krdantas(&self, dhatu: &Dhatu) -> Vec<KrdantaEntry> {
+        let krts: Vec<Krt> = BaseKrt::iter()
+            .map(|b| Krt::Base(*b))
+            .chain(Unadi::iter().map(|x| Krt::Unadi(*x)))
+            .collect();
+
+        let results: Vec<(Krt, Vec<Prakriya>)> = krts
+            .par_iter()
+            .map(|krt| {
+                let spec = Krdanta::new(dhatu.clone(), *krt);
+                (*krt, self.derive_krdantas(&spec))
+            })
+            .collect();
+
+        let mut entries = Vec::new();
+        for (krt, prakriyas) in results {
+            let requires_upapada = match krt {
+                Krt::Base(b) => krt::is_upapada_krt(b),
+                Krt::Unadi(_) => false,
+            };
+            if prakriyas.is_empty() {
+                if requires_upapada {
+                    entries.push(KrdantaEntry::new(krt, None, true, Vec::new()));
+                }
+                continue;
+            }
+
+            let mut groups: Vec<(Option<KrtArtha>, Vec<Prakriya>)> = Vec::new();
+            for p in prakriyas {
+                let key = match p.artha() {
+                    Some(Artha::Krt(a)) => Some(a),
+                    _ => None,
+                };
+                match groups.iter_mut().find(|(k, _)| *k == key) {
+                    Some((_, ps)) => ps.push(p),
+                    None => groups.push((key, vec![p])),
+                }
+            }
+            for (artha, ps) in groups {
+                entries.push(KrdantaEntry::new(krt, artha, false, ps));
+            }
+        }
+        entries
+    }
+
+    /// Returns every taddhitanta that can be derived from `pratipadika`, grouped by meaning.
+    ///
+    /// We try every taddhita-pratyaya. If `artha` is set, we try only the meanings that fall
+    /// under `artha` as defined by `TaddhitaArtha::is_type_of`. Each group's key is the meaning of
+    /// the rule that added the pratyaya, or `None` if that rule has no specific meaning.
+    ///
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::Vyakarana;
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let upagu = Pratipadika::basic("upagu");
+    /// let groups = v.derive_all_taddhitantas(&upagu, Some(TaddhitaArtha::TasyaApatyam));
+    /// let apatya = groups
+    ///     .iter()
+    ///     .find(|(artha, _)| *artha == Some(TaddhitaArtha::TasyaApatyam))
+    ///     .unwrap();
+    /// assert!(apatya.1.iter().any(|p| p.text() == "Opagava"));
+    /// ```
+    pub fn derive_all_taddhitantas(
+        &self,
+        pratipadika: &Pratipadika,
+        artha: Option<TaddhitaArtha>,
+    ) -> Vec<(Option<TaddhitaArtha>, Vec<Prakriya>)> {
+        let arthas: Vec<Option<TaddhitaArtha>> = match artha {
+            Some(parent) => TaddhitaArtha::iter()
+                .filter(|a| a.is_type_of(parent))
+                .map(|a| Some(*a))
+                .collect(),
+            None => vec![None],
+        };
+
+        let mut specs = Vec::new();
+        for artha in &arthas {
+            for taddhita in Taddhita::iter() {
+                let mut builder = Taddhitanta::builder();
+                builder.pratipadika(pratipadika.clone()).taddhita(*taddhita);
+                if let Some(artha) = artha {
+                    builder.artha(*artha);
+                }
+                if let Ok(spec) = builder.build() {
+                    specs.push(spec);
+                }
+            }
+        }
+
+        let results: Vec<Vec<Prakriya>> = specs
+            .par_iter()
+            .map(|spec| self.derive_taddhitantas(spec))
+            .collect();
+
+        let mut groups: Vec<(Option<TaddhitaArtha>, Vec<Prakriya>)> = Vec::new();
+        for p in results.into_iter().flatten() {
+            let key = match p.artha() {
+                Some(Artha::Taddhita(a)) => Some(a),
+                _ => None,
+            };
+            let i = match groups.iter().position(|(k, _)| *k == key) {
+                Some(i) => i,
+                None => {
+                    groups.push((key, Vec::new()));
+                    groups.len() - 1
+                }
+            };
+
+            // The same derivation might be reachable from more than one requested artha.
+            let is_duplicate = groups[i]
+                .1
+                .iter()
+                .any(|q| q.text() == p.text() && applied_rules(q) == applied_rules(&p));
+            if !is_duplicate {
+                groups[i].1.push(p);
+            }
+        }
+        groups
     }
 
     /// Returns all possible stryanta prakriyas that can be derived with the given initial
@@ -311,6 +656,45 @@ impl Vyakarana {
         stack.prakriyas()
     }
 
+    /// Returns the lingas that `pratipadika` may take according to the Paniniya-Linganushasanam,
+    /// along with the rule that allows each linga.
+    ///
+    /// `pratipadika` may be a basic stem or a derived one, such as a krdanta or taddhitanta. If
+    /// no rule applies, the result is empty.
+    ///
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::{Rule, Vyakarana};
+    /// # use vidyut_prakriya::args::*;
+    /// let v = Vyakarana::new();
+    /// let lingas = v.lingas_of(&Pratipadika::basic("Gfta"));
+    /// assert_eq!(
+    ///     lingas,
+    ///     vec![
+    ///         (Linga::Pum, Rule::Linganushasana("177")),
+    ///         (Linga::Napumsaka, Rule::Linganushasana("177")),
+    ///     ]
+    /// );
+    /// ```
+    pub fn lingas_of(&self, pratipadika: &Pratipadika) -> Vec<(Linga, Rule)> {
+        let mut stack = self.create_prakriya_stack();
+        stack.find_all(|p| ashtadhyayi::derive_pratipadika(p, pratipadika));
+
+        let mut ret = Vec::new();
+        for mut p in stack.prakriyas() {
+            if let Some((rule, lingas)) = linganushasanam::decide_lingas(&mut p) {
+                for linga in lingas {
+                    if !ret.contains(&(linga, rule)) {
+                        ret.push((linga, rule));
+                    }
+                }
+            }
+        }
+        ret
+    }
+
     /// Returns all possible sandhi results that follow from the given initial conditions.
     ///
     ///
@@ -330,6 +714,15 @@ impl Vyakarana {
 
     /// Returns all possible sandhi results that follow from the given initial conditions.
     ///
+    /// We derive every variant of every pada and return results for every combination of these
+    /// variants. After combining the padas, we run the main rules and the tripadi over the entire
+    /// vakya so that sandhi rules can apply across pada boundaries. If a tinanta pada sets
+    /// `Tinanta::pada`, we use only the variants that use that pada.
+    ///
+    /// Each result's `rule_choices` starts with the choices made by each of its padas, so
+    /// `Prakriya::diff` can explain how two vakyas differ. If `log_steps` is set, each result's
+    /// history also contains the history of each of its padas.
+    ///
     ///
     /// ### Example
     ///
@@ -337,21 +730,103 @@ impl Vyakarana {
     /// # use vidyut_prakriya::Vyakarana;
     /// # use vidyut_prakriya::Error;
     /// # use vidyut_prakriya::args::*;
-    ///
     /// let v = Vyakarana::new();
+    /// let gacchati = Tinanta::new(
+    ///     Dhatu::mula("ga\\mx~", Gana::Bhvadi),
+    ///     Prayoga::Kartari,
+    ///     Lakara::Lat,
+    ///     Purusha::Prathama,
+    ///     Vacana::Eka,
+    /// );
+    /// let atra = Pada::from_text("atra");
+    /// let prakriyas = v.derive_vakyas(&[gacchati.into(), atra]);
+    /// assert_eq!(prakriyas[0].text(), "gacCatyatra");
     /// # Ok::<(), Error>(())
+    /// ```
     pub fn derive_vakyas(&self, padas: &[Pada]) -> Vec<Prakriya> {
-        let mut stack = self.create_prakriya_stack();
-        stack.find_all(|p| ashtadhyayi::derive_vakya(p, padas));
-        stack.prakriyas()
+        let pada_variants: Vec<Vec<Prakriya>> = padas
+            .iter()
+            .map(|pada| {
+                let mut stack = self.create_prakriya_stack();
+                stack.find_all(|p| ashtadhyayi::derive_pada(p, pada));
+                let (mut prakriyas, mut diagnostics) = stack.prakriyas_and_diagnostics();
+                if let Pada::Tinanta(t) = pada {
+                    if let Some(pada) = t.pada() {
+                        retain_pada(pada, &mut prakriyas, &mut diagnostics);
+                    }
+                }
+                prakriyas
+            })
+            .collect();
+
+        let mut ret = Vec::new();
+        for combination in combinations(&pada_variants) {
+            let mut stack = self.create_prakriya_stack();
+            stack.find_all(|p| ashtadhyayi::derive_vakya(p, &combination));
+
+            let pada_choices: Vec<_> = combination
+                .iter()
+                .flat_map(|p| p.rule_choices().iter().copied())
+                .collect();
+            ret.extend(stack.prakriyas().into_iter().map(|mut p| {
+                p.prepend_rule_choices(&pada_choices);
+                p
+            }));
+        }
+        ret
     }
 
     /// Creates a prakriya stack that generates prakriyas according to our derivation options.
     fn create_prakriya_stack(&self) -> PrakriyaStack {
         PrakriyaStack::new(self.log_steps, self.is_chandasi, self.use_svaras)
+            .with_fixed_choices(self.rule_choices.clone())
     }
 }
 
+/// Keeps only the prakriyas that use `pada`, and adds a diagnostic for each prakriya we remove.
+fn retain_pada(pada: DhatuPada, prakriyas: &mut Vec<Prakriya>, diagnostics: &mut Vec<Diagnostic>) {
+    prakriyas.retain(|p| {
+        let is_match = match pada {
+            DhatuPada::Parasmai => p.has_tag(pada.as_tag()) && !p.has_tag(Tag::AmAtmanepada),
+            DhatuPada::Atmane => p.has_tag_in(&[pada.as_tag(), Tag::AmAtmanepada]),
+        };
+        if !is_match {
+            diagnostics.push(Diagnostic::PadaMismatch {
+                requested: pada,
+                text: p.text(),
+            });
+        }
+        is_match
+    });
+}
+
+/// Returns the rules applied in `p`, ignoring any debug steps.
+fn applied_rules(p: &Prakriya) -> Vec<Rule> {
+    p.history()
+        .iter()
+        .map(|step| step.rule())
+        .filter(|rule| !rule.code().trim().is_empty())
+        .collect()
+}
+
+/// Returns every way to choose one item from each list in `lists`.
+fn combinations<T>(lists: &[Vec<T>]) -> Vec<Vec<&T>> {
+    let mut ret: Vec<Vec<&T>> = vec![Vec::new()];
+    for list in lists {
+        ret = ret
+            .iter()
+            .flat_map(|prefix| {
+                list.iter().map(move |x| {
+                    let mut combination = prefix.clone();
+                    combination.push(x);
+                    combination
+                })
+            })
+            .collect();
+    }
+    ret
+}
+
 /// A builder for creating an `Vyakarana` struct.
 pub struct VyakaranaBuilder {
     vyakarana: Vyakarana,
@@ -380,7 +855,8 @@ impl VyakaranaBuilder {
 
     /// *(default: false)* Controls whether or not to allow rules marked "chandasi," "mantre," etc..
     ///
-    /// - If `true`, each `Prakriya` will have access to chAndasa rules.
+    /// - If `true`, each `Prakriya` will have access to chAndasa rules, such as the infinitives
+    ///   in 3.4.9 (jIvase, pibaDyE) and the sup variants in 7.1.10 and 7.1.50 (deveBiH, devAsaH).
     ///
     /// - If `false`, each `Prakriya` will use a standard ruleset.
     pub fn is_chandasi(mut self, value: bool) -> Self {
@@ -398,6 +874,41 @@ impl VyakaranaBuilder {
         self
     }
 
+    /// *(default: empty)* Sets optional rules that every derivation must accept or decline.
+    ///
+    /// By default, `Vyakarana` explores both branches of every optional rule and returns all of
+    /// the results. If a rule appears here, `Vyakarana` uses only the branch specified. This is
+    /// useful for following the preferences of a specific commentary or for showing exactly one
+    /// derivation path. If a rule is listed more than once, the first choice takes priority.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use vidyut_prakriya::{Rule, RuleChoice, Vyakarana};
+    /// # use vidyut_prakriya::args::*;
+    /// let bhu = Dhatu::mula("BU", Gana::Bhvadi);
+    /// let args = Tinanta::new(bhu, Prayoga::Kartari, Lakara::Lot, Purusha::Prathama, Vacana::Eka);
+    ///
+    /// // By default, we get all three forms: "Bavatu", "BavatAt", and "BavatAd".
+    /// let v = Vyakarana::new();
+    /// assert_eq!(v.derive_tinantas(&args).len(), 3);
+    ///
+    /// // But we can require tAtaN (7.1.35) and block the optional *car* substitution of 8.4.56.
+    /// let v = Vyakarana::builder()
+    ///     .rule_choices(&[
+    ///         RuleChoice::Accept(Rule::Ashtadhyayi("7.1.35")),
+    ///         RuleChoice::Decline(Rule::Ashtadhyayi("8.4.56")),
+    ///     ])
+    ///     .build();
+    /// let prakriyas = v.derive_tinantas(&args);
+    /// assert_eq!(prakriyas.len(), 1);
+    /// assert_eq!(prakriyas[0].text(), "BavatAd");
+    /// ```
+    pub fn rule_choices(mut self, values: &[RuleChoice]) -> Self {
+        self.vyakarana.rule_choices = values.to_vec();
+        self
+    }
+
     /// Creates an `Vyakarana` struct.
     pub fn build(self) -> Vyakarana {
         self.vyakarana
@@ -6,6 +6,8 @@ for it quite easily. In development, we recommend using these bindings through t
 command, which will also start a small Python webserver.
 
 The main struct of interest is `Vidyut`, which wraps all of vidyut-prakriya's high-level APIs.
+Its most general method is `derive`, which accepts a JSON-style request object that can describe
+any derivation that `Vyakarana` supports.
 
 Although these bindings are usable and reliable, we want to improve their ergonomics so that
 JavaScript callers can use them more idiomatically.
@@ -14,7 +16,7 @@ use crate::args::*;
 use crate::core::Rule;
 use crate::core::{Prakriya, Step, StepTerm};
 use crate::dhatupatha::Dhatupatha;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 extern crate console_error_panic_hook;
 
 use crate::Vyakarana;
@@ -118,6 +120,87 @@ fn to_web_prakriyas(prakriyas: &[Prakriya]) -> Vec<WebPrakriya> {
         .collect()
 }
 
+/// Options for creating a `Vyakarana`.
+#[allow(non_snake_case)]
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct WebOptions {
+    /// Whether to use rules that apply only in Vedic Sanskrit.
+    chandasi: bool,
+    /// Whether to derive svaras.
+    svaras: bool,
+    /// Whether to record the derivation history.
+    logSteps: bool,
+}
+
+impl Default for WebOptions {
+    fn default() -> Self {
+        Self {
+            chandasi: false,
+            svaras: false,
+            logSteps: true,
+        }
+    }
+}
+
+impl WebOptions {
+    fn vyakarana(&self) -> Vyakarana {
+        Vyakarana::builder()
+            .is_chandasi(self.chandasi)
+            .use_svaras(self.svaras)
+            .log_steps(self.logSteps)
+            .build()
+    }
+}
+
+/// The arguments to a derivation. Each variant uses the `serde` format of the corresponding type
+/// in `args`, so nested pratipadikas, unadi krts, and namadhatus are all supported.
+#[derive(Deserialize)]
+pub enum WebArgs {
+    /// Arguments for `Vyakarana::derive_dhatus`.
+    Dhatu(Dhatu),
+    /// Arguments for `Vyakarana::derive_tinantas`.
+    Tinanta(Tinanta),
+    /// Arguments for `Vyakarana::derive_subantas`.
+    Subanta(Subanta),
+    /// Arguments for `Vyakarana::derive_krdantas`.
+    Krdanta(Krdanta),
+    /// Arguments for `Vyakarana::derive_taddhitantas`.
+    Taddhitanta(Taddhitanta),
+    /// Arguments for `Vyakarana::derive_samasas`.
+    Samasa(Samasa),
+    /// Arguments for `Vyakarana::derive_stryantas`.
+    Stryanta(Pratipadika),
+    /// Arguments for `Vyakarana::derive_vakyas`.
+    Vakya(Vec<Pada>),
+}
+
+/// A request for `Vidyut::derive`.
+#[derive(Deserialize)]
+pub struct WebRequest {
+    /// The arguments to derive.
+    args: WebArgs,
+    /// Options for the derivation.
+    #[serde(default)]
+    options: WebOptions,
+}
+
+impl WebRequest {
+    fn derive(&self) -> Vec<Prakriya> {
+        let v = self.options.vyakarana();
+        match &self.args {
+            WebArgs::Dhatu(x) => v.derive_dhatus(x),
+            WebArgs::Tinanta(x) => v.derive_tinantas(x),
+            WebArgs::Subanta(x) => v.derive_subantas(x),
+            WebArgs::Krdanta(x) => v.derive_krdantas(x),
+            WebArgs::Taddhitanta(x) => v.derive_taddhitantas(x),
+            WebArgs::Samasa(x) => v.derive_samasas(x),
+            WebArgs::Stryanta(x) => v.derive_stryantas(x),
+            WebArgs::Vakya(x) => v.derive_vakyas(x),
+        }
+    }
+}
+
 /// Expands a mula dhatu by adding sanadi-pratyayas and upasargas, as needed.
 fn try_expand_dhatu(dhatu: &Dhatu, sanadi: Option<Sanadi>, upasarga: Option<String>) -> Dhatu {
     let mut ret = dhatu.clone();
@@ -156,9 +239,49 @@ impl Vidyut {
         }
     }
 
+    /// Derives all prakriyas for the given request.
+    ///
+    /// `request` is an object with two fields:
+    ///
+    /// - `args`, which describes the derivation as a single-key object whose key is one of
+    ///   `Dhatu`, `Tinanta`, `Subanta`, `Krdanta`, `Taddhitanta`, `Samasa`, `Stryanta`, or
+    ///   `Vakya`. For example:
+    ///
+    ///   ```json
+    ///   {"Subanta": {"pratipadika": {"Basic": {"text": "deva"}},
+    ///                "linga": "Pum", "vibhakti": "Prathama", "vacana": "Eka"}}
+    ///   ```
+    ///
+    /// - `options` (optional), which may set `chandasi`, `svaras`, and `logSteps`.
+    ///
+    /// To refer to a dhatu in our Dhatupatha, use the value returned by `dhatu`.
+    pub fn derive(&self, request: JsValue) -> JsValue {
+        let prakriyas = match serde_wasm_bindgen::from_value::<WebRequest>(request) {
+            Ok(request) => request.derive(),
+            Err(e) => {
+                error(&format!("[vidyut] Invalid request: {e}"));
+                Vec::new()
+            }
+        };
+        let web_prakriyas = to_web_prakriyas(&prakriyas);
+        serde_wasm_bindgen::to_value(&web_prakriyas).expect("wasm")
+    }
+
+    /// Returns the dhatu with the given code in a format that `derive` accepts, or `undefined` if
+    /// the code is not in our Dhatupatha.
+    pub fn dhatu(&self, code: &str) -> JsValue {
+        match self.dhatupatha.get(code) {
+            Some(dhatu) => serde_wasm_bindgen::to_value(dhatu).expect("wasm"),
+            None => {
+                error(&format!("[vidyut] Dhatu code not found: {code}"));
+                JsValue::UNDEFINED
+            }
+        }
+    }
+
     /// Wrapper for `Vyakarana::derive_tinantas`.
     ///
-    /// TODO: how might we reduce the number of arguments here?
+    /// For more options, use `derive`.
     #[allow(clippy::too_many_arguments)]
     #[allow(non_snake_case)]
     pub fn deriveTinantas(
@@ -262,3 +385,33 @@ impl Vidyut {
         }
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn derive(json: &str) -> Vec<String> {
+        let request: WebRequest = serde_json::from_str(json).unwrap();
+        request.derive().iter().map(|p| p.text()).collect()
+    }
+
+    #[test]
+    fn derive_with_defaults() {
+        let texts = derive(
+            r#"{"args": {"Subanta": {"pratipadika": {"Basic": {"text": "deva"}},
+                "linga": "Pum", "vibhakti": "Prathama", "vacana": "Eka"}}}"#,
+        );
+        assert_eq!(texts, vec!["devaH"]);
+    }
+
+    #[test]
+    fn derive_with_unadi_and_options() {
+        let texts = derive(
+            r#"{"args": {"Krdanta": {"dhatu": {"Mula": {"upadesha": "qupa\\ca~^z", "gana": "Bhvadi"}},
+                "krt": {"Unadi": "zwran"}}},
+                "options": {"svaras": true, "logSteps": false}}"#,
+        );
+        assert_eq!(texts, vec!["pa/ktra"]);
+    }
+}
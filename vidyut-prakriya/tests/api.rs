@@ -9,7 +9,7 @@ use test_utils::assert_has_results;
 use vidyut_prakriya::args::BaseKrt as Krt;
 use vidyut_prakriya::args::Gana::*;
 use vidyut_prakriya::args::*;
-use vidyut_prakriya::Vyakarana;
+use vidyut_prakriya::{AbortReason, AnalysisArgs, Analyzer, Diagnostic, Prakriya, Rule, Vyakarana};
 
 fn assert_derive_dhatu(dhatu: Dhatu, expected: &[&str]) {
     let v = Vyakarana::new();
@@ -129,6 +129,46 @@ fn derive_tinantas_with_invalid_dhatu() {
     assert_has_results(prakriyas, &[]);
 }
 
+#[test]
+fn derive_tinantas_with_diagnostics() {
+    let v = Vyakarana::new();
+
+    let args = Tinanta::builder()
+        .dhatu(Dhatu::mula("k", Gana::Tanadi))
+        .prayoga(Prayoga::Kartari)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .lakara(Lakara::Lat)
+        .build()
+        .unwrap();
+    let (prakriyas, diagnostics) = v.derive_tinantas_with_diagnostics(&args);
+    assert!(prakriyas.is_empty());
+    assert!(matches!(diagnostics[..], [Diagnostic::InvalidArgument(_)]));
+
+    // yaN is allowed only after dhatus that start with a consonant.
+    let edh = Dhatu::mula("eDa~\\", Bhvadi).with_sanadi(&[Sanadi::yaN]);
+    let args = args.with_dhatu(edh);
+    let (prakriyas, diagnostics) = v.derive_tinantas_with_diagnostics(&args);
+    assert!(prakriyas.is_empty());
+    assert!(!diagnostics.is_empty());
+    for d in &diagnostics {
+        assert!(matches!(
+            d,
+            Diagnostic::Aborted {
+                reason: AbortReason::SanadiNotAdded(Sanadi::yaN),
+                last_rule: Some(_),
+                ..
+            }
+        ));
+    }
+
+    // Successful derivations have no diagnostics.
+    let args = args.with_dhatu(Dhatu::mula("BU", Bhvadi));
+    let (prakriyas, diagnostics) = v.derive_tinantas_with_diagnostics(&args);
+    assert_has_results(prakriyas, &["Bavati"]);
+    assert!(diagnostics.is_empty());
+}
+
 #[test]
 fn derive_krdantas() {
     let v = Vyakarana::new();
@@ -153,3 +193,595 @@ fn derive_krdantas() {
     let prakriyas = v.derive_krdantas(&krdanta);
     assert_has_results(prakriyas, &["kArayitvA"]);
 }
+
+#[test]
+fn lingas_of_basic_and_derived_pratipadikas() {
+    use vidyut_prakriya::Rule::Linganushasana as L;
+
+    let v = Vyakarana::new();
+
+    // Basic pratipadikas.
+    let rajan = Pratipadika::basic("rAjan");
+    assert_eq!(v.lingas_of(&rajan), vec![(Linga::Pum, L("48"))]);
+    let bhumi = Pratipadika::basic("BUmi");
+    assert_eq!(v.lingas_of(&bhumi), vec![(Linga::Stri, L("18"))]);
+
+    // krdantas
+    let kfti = Krdanta::new(Dhatu::mula("qukf\\Y", Tanadi), Krt::ktin);
+    assert_eq!(v.lingas_of(&kfti.into()), vec![(Linga::Stri, L("9"))]);
+    let paka = Krdanta::new(Dhatu::mula("qupa\\ca~^z", Bhvadi), Krt::GaY);
+    assert_eq!(v.lingas_of(&paka.into()), vec![(Linga::Pum, L("36"))]);
+
+    // taddhitantas
+    let suklatva = Taddhitanta::builder()
+        .pratipadika(Pratipadika::basic("Sukla"))
+        .taddhita(Taddhita::tva)
+        .build()
+        .unwrap();
+    assert_eq!(
+        v.lingas_of(&suklatva.into()),
+        vec![(Linga::Napumsaka, L("121"))]
+    );
+
+    // More than one linga. Later rules (here, the rule for stems in -Ra) don't override this.
+    assert_eq!(
+        v.lingas_of(&Pratipadika::basic("bARa")),
+        vec![(Linga::Pum, L("47")), (Linga::Napumsaka, L("47"))]
+    );
+
+    // No rule applies.
+    assert!(v.lingas_of(&Pratipadika::basic("agni")).is_empty());
+}
+
+#[test]
+fn derive_all_taddhitantas_grouped_by_artha() {
+    use TaddhitaArtha::*;
+
+    let v = Vyakarana::new();
+    let texts = |groups: &[(Option<TaddhitaArtha>, Vec<Prakriya>)], artha| {
+        let mut ret: Vec<String> = groups
+            .iter()
+            .filter(|(a, _)| *a == artha)
+            .flat_map(|(_, ps)| ps.iter().map(|p| p.text()))
+            .collect();
+        ret.sort();
+        ret
+    };
+
+    // All arthas.
+    let upagu = Pratipadika::basic("upagu");
+    let groups = v.derive_all_taddhitantas(&upagu, None);
+    assert_eq!(texts(&groups, Some(TasyaApatyam)), vec!["Opagava"]);
+    assert_eq!(
+        texts(&groups, Some(TasyaBhava)),
+        vec!["upagutA", "upagutva"]
+    );
+    assert_eq!(
+        texts(&groups, Some(TadAsyaAstiAsmin)),
+        vec!["upagUvala", "upagumat"]
+    );
+
+    // A parent artha also includes its subtypes.
+    let garga = Pratipadika::basic("garga");
+    let groups = v.derive_all_taddhitantas(&garga, Some(TasyaApatyam));
+    assert!(texts(&groups, Some(TasyaApatyam)).contains(&"gArgi".to_string()));
+    assert!(texts(&groups, Some(Gotra)).contains(&"gArgya".to_string()));
+    assert!(groups
+        .iter()
+        .all(|(a, _)| a.map_or(true, |a| a.is_type_of(TasyaApatyam))));
+}
+
+#[test]
+fn derive_all_krdantas_with_arthas_and_upapadas() {
+    let v = Vyakarana::new();
+    let pac = Dhatu::mula("qupa\\ca~^z", Bhvadi);
+    let entries = v.derive_all_krdantas(&pac);
+    let find = |krt: vidyut_prakriya::args::Krt| {
+        entries
+            .iter()
+            .find(|e| e.krt() == krt)
+            .unwrap_or_else(|| panic!("{krt:?}"))
+    };
+
+    // Basic krts, with and without a specific artha.
+    let ghan = find(Krt::GaY.into());
+    assert_eq!(ghan.artha(), Some(KrtArtha::Bhava));
+    assert!(ghan.prakriyas().iter().any(|p| p.text() == "pAka"));
+    let tfc = find(Krt::tfc.into());
+    assert_eq!(tfc.artha(), None);
+    assert!(tfc.prakriyas().iter().any(|p| p.text() == "paktf"));
+
+    // Unadi pratyayas.
+    let zwran = find(Unadi::zwran.into());
+    assert!(zwran.prakriyas().iter().any(|p| p.text() == "paktra"));
+
+    // Krts that need an upapada are flagged but have no prakriyas.
+    let wa = find(Krt::wa.into());
+    assert!(wa.requires_upapada());
+    assert!(wa.prakriyas().is_empty());
+    assert!(entries
+        .iter()
+        .all(|e| e.requires_upapada() == e.prakriyas().is_empty()));
+}
+
+#[test]
+fn args_round_trip_through_json() {
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let json = serde_json::to_string(value).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    let bhu = Dhatu::mula("BU", Bhvadi).with_sanadi(&[Sanadi::san]);
+    assert_eq!(round_trip(&bhu), bhu);
+
+    let bhavati = Tinanta::new(
+        Dhatu::mula("BU", Bhvadi),
+        Prayoga::Kartari,
+        Lakara::Lat,
+        Purusha::Prathama,
+        Vacana::Eka,
+    );
+    assert_eq!(round_trip(&bhavati), bhavati);
+
+    // A krdanta inside a samasa inside a taddhitanta.
+    let kartf = Krdanta::new(Dhatu::mula("qukf\\Y", Tanadi), Krt::tfc);
+    let samasa = Samasa::builder()
+        .padas(vec![
+            Subanta::new("grAma", Linga::Pum, Vibhakti::Sasthi, Vacana::Eka),
+            Subanta::new(kartf, Linga::Pum, Vibhakti::Prathama, Vacana::Eka),
+        ])
+        .samasa_type(SamasaType::Tatpurusha)
+        .build()
+        .unwrap();
+    let taddhitanta = Taddhitanta::builder()
+        .pratipadika(samasa.into())
+        .taddhita(Taddhita::tva)
+        .artha(TaddhitaArtha::TasyaBhava)
+        .build()
+        .unwrap();
+    assert_eq!(round_trip(&taddhitanta), taddhitanta);
+
+    let subanta = Subanta::new(
+        taddhitanta.clone(),
+        Linga::Napumsaka,
+        Vibhakti::Prathama,
+        Vacana::Eka,
+    );
+    assert_eq!(round_trip(&subanta), subanta);
+
+    let avyaya = Subanta::avyaya(Pratipadika::avyaya("svar"));
+    assert_eq!(round_trip(&avyaya), avyaya);
+
+    let putriya =
+        Dhatu::nama(Pratipadika::basic("putra"), Some(Sanadi::kyac)).with_sanadi(&[Sanadi::Ric]);
+    assert_eq!(round_trip(&putriya), putriya);
+
+    // Declarative requests may omit optional and default fields.
+    let json = r#"{"Mula": {"upadesha": "BU", "gana": "Bhvadi"}}"#;
+    let dhatu: Dhatu = serde_json::from_str(json).unwrap();
+    assert_eq!(dhatu, Dhatu::mula("BU", Bhvadi));
+}
+
+#[test]
+fn args_from_json_are_validated() {
+    // Builder validation applies to JSON as well.
+    let json = r#"{"padas": [], "samasa_type": "Tatpurusha"}"#;
+    let err = serde_json::from_str::<Samasa>(json).unwrap_err();
+    assert!(err.to_string().contains("padas"));
+
+    // Unknown fields are rejected, so that typos in optional fields aren't silently ignored.
+    let json = r#"{"Mula": {"upadesha": "BU", "gana": "Bhvadi", "sanaadi": ["san"]}}"#;
+    assert!(serde_json::from_str::<Dhatu>(json).is_err());
+    let json = r#"{"pratipadika": {"Basic": {"text": "deva"}}, "linga": "Pum",
+        "vibhakti": "Prathama", "vacana": "Eka", "avyaya": true}"#;
+    assert!(serde_json::from_str::<Subanta>(json).is_err());
+
+    // Missing required fields are still rejected.
+    let json = r#"{"pratipadika": {"Basic": {"text": "deva"}}, "linga": "Pum"}"#;
+    assert!(serde_json::from_str::<Subanta>(json).is_err());
+}
+
+#[test]
+fn derive_vakyas_with_all_pada_variants() {
+    let v = Vyakarana::builder().log_steps(true).build();
+
+    let gup = Dhatu::mula("gupU~", Bhvadi);
+    let gopsyati = Tinanta::new(
+        gup,
+        Prayoga::Kartari,
+        Lakara::Lrt,
+        Purusha::Prathama,
+        Vacana::Eka,
+    );
+    let atra = Pada::from_text("atra");
+    let prakriyas = v.derive_vakyas(&[gopsyati.into(), atra]);
+
+    // The history of each result includes the history of its padas, e.g. the rule that adds
+    // `sya`.
+    for p in &prakriyas {
+        assert!(p.history().iter().any(|s| s.rule().code() == "3.1.33"));
+    }
+    assert_has_results(
+        prakriyas,
+        &["gopAyizyatyatra", "gopizyatyatra", "gopsyatyatra"],
+    );
+}
+
+#[test]
+fn derive_vakyas_with_tinanta_pada() {
+    let v = Vyakarana::new();
+
+    let pac = Dhatu::mula("qupa\\ca~^z", Bhvadi);
+    let pacate = Tinanta::builder()
+        .dhatu(pac)
+        .prayoga(Prayoga::Kartari)
+        .lakara(Lakara::Lat)
+        .purusha(Purusha::Prathama)
+        .vacana(Vacana::Eka)
+        .pada(DhatuPada::Atmane)
+        .build()
+        .unwrap();
+    let atra = Pada::from_text("atra");
+    let prakriyas = v.derive_vakyas(&[atra, pacate.into()]);
+
+    // `Tinanta::pada` excludes the parasmaipada form `pacati`.
+    assert_has_results(prakriyas, &["atrapacate"]);
+}
+
+#[test]
+fn diff_explains_divergent_vakyas() {
+    let v = Vyakarana::new();
+    let bhavatu = Tinanta::new(
+        Dhatu::mula("BU", Bhvadi),
+        Prayoga::Kartari,
+        Lakara::Lot,
+        Purusha::Prathama,
+        Vacana::Eka,
+    );
+    let atra = Pada::from_text("atra");
+    let prakriyas = v.derive_vakyas(&[bhavatu.into(), atra]);
+    let find = |text| {
+        prakriyas
+            .iter()
+            .find(|p| p.text() == text)
+            .expect("has result")
+    };
+
+    // The two vakyas split on a choice that was made while deriving the tinanta.
+    let diff = find("Bavatvatra").diff(find("BavatAdatra"));
+    let choice = diff.first_divergence().expect("diverges");
+    assert_eq!(choice.rule(), Some(Rule::Ashtadhyayi("7.1.35")));
+}
+
+#[test]
+fn analyze_tinantas_and_krdantas() {
+    let bhu = Dhatu::mula("BU", Bhvadi);
+    let gam = Dhatu::mula("ga\\mx~", Bhvadi);
+    let analyzer = Analyzer::builder()
+        .dhatus(&[bhu.clone(), gam.clone()])
+        .lakaras(&[Lakara::Lat])
+        .krts(&[Krt::ktavatu, Krt::ktvA])
+        .build();
+
+    let analyses = analyzer.analyze("Bavati");
+    assert_eq!(analyses.len(), 1);
+    let expected = Tinanta::new(
+        bhu,
+        Prayoga::Kartari,
+        Lakara::Lat,
+        Purusha::Prathama,
+        Vacana::Eka,
+    );
+    assert_eq!(analyses[0].args(), &AnalysisArgs::Tinanta(expected));
+    assert_eq!(analyses[0].prakriyas()[0].text(), "Bavati");
+
+    let analyses = analyzer.analyze("gatavAn");
+    let gatavat = Pratipadika::from(Krdanta::new(gam.clone(), Krt::ktavatu));
+    let expected = Subanta::new(gatavat, Linga::Pum, Vibhakti::Prathama, Vacana::Eka);
+    assert!(analyses
+        .iter()
+        .any(|a| a.args() == &AnalysisArgs::Subanta(expected.clone())));
+
+    // Avyayas are indexed only as krdantas.
+    let analyses = analyzer.analyze("gatvA");
+    assert_eq!(analyses.len(), 1);
+    let expected = Krdanta::new(gam, Krt::ktvA);
+    assert_eq!(analyses[0].args(), &AnalysisArgs::Krdanta(expected));
+
+    // Unknown words have no analyses.
+    assert!(analyzer.analyze("gamati").is_empty());
+    assert!(analyzer.analyze("").is_empty());
+}
+
+#[test]
+fn analyze_subantas_and_taddhitantas() {
+    let analyzer = Analyzer::builder()
+        .pratipadikas(&[(Pratipadika::basic("upagu"), Linga::Pum)])
+        .taddhitas(&[Taddhita::aR])
+        .build();
+
+    let analyses = analyzer.analyze("upagunA");
+    assert_eq!(analyses.len(), 1);
+    let expected = Subanta::new(
+        Pratipadika::basic("upagu"),
+        Linga::Pum,
+        Vibhakti::Trtiya,
+        Vacana::Eka,
+    );
+    assert_eq!(analyses[0].args(), &AnalysisArgs::Subanta(expected));
+
+    let analyses = analyzer.analyze("Opagava");
+    assert!(analyses
+        .iter()
+        .any(|a| matches!(a.args(), AnalysisArgs::Taddhitanta(_))));
+    assert!(analyzer.contains("OpagavaH"));
+}
+
+#[test]
+fn derive_with_fixed_rule_choices() {
+    use vidyut_prakriya::{Rule, RuleChoice};
+
+    let sam_kr = Dhatu::mula("qukf\\Y", Tanadi).with_prefixes(&["sam"]);
+
+    let v = Vyakarana::builder()
+        .rule_choices(&[RuleChoice::Accept(Rule::Ashtadhyayi("6.1.137"))])
+        .build();
+    assert_has_results(v.derive_dhatus(&sam_kr), &["saMskf"]);
+
+    let v = Vyakarana::builder()
+        .rule_choices(&[RuleChoice::Decline(Rule::Ashtadhyayi("6.1.137"))])
+        .build();
+    assert_has_results(v.derive_dhatus(&sam_kr), &["saNkf"]);
+
+    // Choices for rules that the derivation doesn't use have no effect.
+    let v = Vyakarana::builder()
+        .rule_choices(&[RuleChoice::Decline(Rule::Ashtadhyayi("7.1.35"))])
+        .build();
+    assert_has_results(v.derive_dhatus(&sam_kr), &["saMskf", "saNkf"]);
+}
+
+#[test]
+fn inspect_and_serialize_history() {
+    use vidyut_prakriya::Rule;
+
+    let v = Vyakarana::new();
+    let args = Tinanta::new(
+        Dhatu::mula("BU", Bhvadi),
+        Prayoga::Kartari,
+        Lakara::Lat,
+        Purusha::Prathama,
+        Vacana::Eka,
+    );
+    let prakriyas = v.derive_tinantas(&args);
+    let p = &prakriyas[0];
+
+    // Terms expose their tags.
+    let last = p.history().last().unwrap();
+    assert!(last.result().iter().any(|t| t.has_tag("Dhatu")));
+    assert!(last
+        .result()
+        .iter()
+        .any(|t| t.tags().any(|tag| tag == "Pratyaya")));
+
+    // Rules expose their text.
+    assert!(
+        p.history()
+            .iter()
+            .any(|s| s.rule() == Rule::Ashtadhyayi("3.1.68")
+                && s.rule().text() == Some("kartari Sap"))
+    );
+
+    let json = serde_json::to_value(p).unwrap();
+    assert_eq!(json["text"], "Bavati");
+    assert_eq!(json["history"][0]["rule"]["Ashtadhyayi"], "1.3.1");
+    assert!(json["history"][0]["result"][0]["tags"]
+        .as_array()
+        .unwrap()
+        .contains(&"Dhatu".into()));
+}
+
+#[test]
+fn inspect_svaras() {
+    use vidyut_prakriya::Svara;
+
+    let v = Vyakarana::builder().use_svaras(true).build();
+    let args = Tinanta::new(
+        Dhatu::mula("BU", Bhvadi),
+        Prayoga::Kartari,
+        Lakara::Lat,
+        Purusha::Prathama,
+        Vacana::Eka,
+    );
+    let prakriyas = v.derive_tinantas(&args);
+    let last = prakriyas[0].history().last().unwrap();
+    assert!(last.result().iter().any(|t| t.svara().is_some()));
+    assert!(last
+        .result()
+        .iter()
+        .any(|t| matches!(t.svara(), Some(Svara::Anudatta))));
+}
+
+#[test]
+fn render_text_with_svaras() {
+    use vidyut_prakriya::SvaraNotation;
+
+    let v = Vyakarana::builder().use_svaras(true).build();
+    let args = Tinanta::new(
+        Dhatu::mula("BU", Bhvadi),
+        Prayoga::Kartari,
+        Lakara::Lat,
+        Purusha::Prathama,
+        Vacana::Eka,
+    );
+    let prakriyas = v.derive_tinantas(&args);
+    let p = &prakriyas[0];
+    assert_eq!(p.text_with_svaras(SvaraNotation::Slp1), "Bava^ti");
+    assert_eq!(p.text_with_svaras(SvaraNotation::Iast), "bha\u{301}vati");
+    assert_eq!(p.text_with_svaras(SvaraNotation::Devanagari), "भव॑ति");
+
+    let last = p.history().last().unwrap();
+    assert_eq!(last.text_with_svaras(SvaraNotation::Slp1), "Bava^ti");
+
+    // Upadeshas without a svara keep their markers.
+    let args = Tinanta::new(
+        Dhatu::mula("qupa\\ca~^z", Bhvadi),
+        Prayoga::Kartari,
+        Lakara::Lat,
+        Purusha::Prathama,
+        Vacana::Eka,
+    );
+    let prakriyas = v.derive_tinantas(&args);
+    let first = &prakriyas[0].history()[0];
+    assert_eq!(first.text_with_svaras(SvaraNotation::Slp1), "qupa\\ca~^z");
+}
+
+#[test]
+fn derive_tinanta_paradigm() {
+    let v = Vyakarana::new();
+    let kr = Dhatu::mula("qukf\\Y", Tanadi);
+
+    let paradigm = v.derive_tinanta_paradigm(&kr, Prayoga::Kartari, None);
+    // let-lakara is excluded by default.
+    assert!(paradigm.get(Lakara::Let).is_none());
+    assert_eq!(paradigm.tables().len(), 10);
+
+    let lat = paradigm.get(Lakara::Lat).unwrap();
+    assert_eq!(lat.lakara(), Lakara::Lat);
+    let texts: Vec<_> = lat
+        .get(Purusha::Prathama, Vacana::Eka)
+        .iter()
+        .map(|p| p.text())
+        .collect();
+    assert!(texts.contains(&"karoti".to_string()));
+    assert!(texts.contains(&"kurute".to_string()));
+
+    let lot = paradigm.get(Lakara::Lot).unwrap();
+    assert!(lot
+        .get(Purusha::Uttama, Vacana::Bahu)
+        .iter()
+        .any(|p| p.text() == "karavAma"));
+
+    // With an explicit pada.
+    let paradigm = v.derive_tinanta_paradigm(&kr, Prayoga::Kartari, Some(DhatuPada::Atmane));
+    let lat = paradigm.get(Lakara::Lat).unwrap();
+    let texts: Vec<_> = lat
+        .get(Purusha::Prathama, Vacana::Eka)
+        .iter()
+        .map(|p| p.text())
+        .collect();
+    assert_eq!(texts, vec!["kurute"]);
+}
+
+#[test]
+fn tinanta_paradigm_matches_derive_tinantas() {
+    fn sorted_texts(prakriyas: &[Prakriya]) -> Vec<String> {
+        let mut ret: Vec<_> = prakriyas.iter().map(|p| p.text()).collect();
+        ret.sort();
+        ret
+    }
+
+    let v = Vyakarana::new();
+    let dhatus = [
+        Dhatu::mula("BU", Bhvadi),
+        Dhatu::mula("qukf\\Y", Tanadi).with_sanadi(&[Sanadi::san]),
+        Dhatu::mula("gupU~", Bhvadi),
+    ];
+    for dhatu in &dhatus {
+        for prayoga in [Prayoga::Kartari, Prayoga::Karmani] {
+            let paradigm = v.derive_tinanta_paradigm(dhatu, prayoga, None);
+            for table in paradigm.tables() {
+                for purusha in Purusha::iter() {
+                    for vacana in Vacana::iter() {
+                        let args =
+                            Tinanta::new(dhatu.clone(), prayoga, table.lakara(), *purusha, *vacana);
+                        assert_eq!(
+                            sorted_texts(table.get(*purusha, *vacana)),
+                            sorted_texts(&v.derive_tinantas(&args)),
+                            "{args:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn derive_subanta_paradigm() {
+    let v = Vyakarana::new();
+    let paradigm = v.derive_subanta_paradigm(&Pratipadika::basic("deva"), Linga::Pum);
+    assert!(!paradigm.is_empty());
+
+    let expected = [
+        (Vibhakti::Prathama, Vacana::Eka, "devaH"),
+        (Vibhakti::Prathama, Vacana::Bahu, "devAH"),
+        (Vibhakti::Trtiya, Vacana::Eka, "devena"),
+        (Vibhakti::Sasthi, Vacana::Dvi, "devayoH"),
+        (Vibhakti::Saptami, Vacana::Bahu, "devezu"),
+        (Vibhakti::Sambodhana, Vacana::Eka, "deva"),
+    ];
+    for (vibhakti, vacana, text) in expected {
+        let cell = paradigm.get(vibhakti, vacana);
+        assert!(cell.iter().any(|p| p.text() == text), "{text}");
+    }
+
+    for vibhakti in Vibhakti::iter() {
+        for vacana in Vacana::iter() {
+            let args = Subanta::new(Pratipadika::basic("deva"), Linga::Pum, *vibhakti, *vacana);
+            let expected: Vec<_> = v.derive_subantas(&args).iter().map(|p| p.text()).collect();
+            let actual: Vec<_> = paradigm
+                .get(*vibhakti, *vacana)
+                .iter()
+                .map(|p| p.text())
+                .collect();
+            assert_eq!(actual, expected);
+        }
+    }
+}
+
+#[test]
+fn diff_explains_divergent_prakriyas() {
+    let v = Vyakarana::new();
+    let args = Tinanta::new(
+        Dhatu::mula("BU", Bhvadi),
+        Prayoga::Kartari,
+        Lakara::Lot,
+        Purusha::Prathama,
+        Vacana::Eka,
+    );
+    let prakriyas = v.derive_tinantas(&args);
+    let find = |text| {
+        prakriyas
+            .iter()
+            .find(|p| p.text() == text)
+            .expect("has result")
+    };
+    let bhavatat = find("BavatAt");
+    let bhavatad = find("BavatAd");
+
+    // The two forms split on the optional rule 8.4.56.
+    let diff = bhavatat.diff(bhavatad);
+    let choice = diff.first_divergence().expect("diverges");
+    assert_eq!(choice.rule(), Some(Rule::Ashtadhyayi("8.4.56")));
+    assert!(diff.num_shared_steps() > 0);
+
+    // 8.4.56 applies only to BavatAt, and 8.4.68 then sees different terms.
+    let steps = diff.steps();
+    assert!(steps[0].first().is_some() && steps[0].second().is_none());
+    let last = steps.last().expect("has steps");
+    assert_eq!(last.rule(), Rule::Ashtadhyayi("8.4.68"));
+    let texts: Vec<_> = last
+        .term_diffs()
+        .iter()
+        .map(|t| (t.first().map(|x| x.text()), t.second().map(|x| x.text())))
+        .collect();
+    assert_eq!(texts, vec![(Some("tAt"), Some("tAd"))]);
+
+    // A prakriya has no divergence from itself.
+    let diff = bhavatat.diff(bhavatat);
+    assert!(diff.first_divergence().is_none());
+    assert!(diff.steps().is_empty());
+}
@@ -144,6 +144,11 @@ fn sutra_3_1_12() {
 fn sutra_3_1_13() {
     let kyas = |prati| Dhatu::nama(prati, None);
     assert_has_lat(&[], &kyas(p("lohita")), &["lohitAyati", "lohitAyate"]);
+
+    // With an explicit pratyaya.
+    let kyas = |prati| sanadi(prati, Sanadi::kyaz);
+    assert_has_lat(&[], &kyas(p("lohita")), &["lohitAyati", "lohitAyate"]);
+    assert_has_lat(&[], &kyas(p("putra")), &[]);
 }
 
 #[test]
@@ -236,6 +241,11 @@ fn sutra_3_1_20() {
     assert_has_ta(&["pari"], &nama("pucCa"), Lat, &["paripucCayate"]);
     assert_has_ta(&["sam"], &nama("BARqa"), Lat, &["samBARqayate"]);
     assert_has_ta(&["sam"], &nama("cIvara"), Lat, &["saYcIvarayate"]);
+
+    // With an explicit pratyaya.
+    let nin = |prati| sanadi(p(prati), Sanadi::RiN);
+    assert_has_ta(&["ud"], &nin("pucCa"), Lat, &["utpucCayate"]);
+    assert_has_ta(&[], &nin("putra"), Lat, &[]);
 }
 
 #[ignore]
@@ -375,6 +385,45 @@ fn sutra_3_1_32() {
     assert_has_tip(&[], &sanadi(p("putra"), kAmyac), Lat, &["putrakAmyati"]);
 }
 
+#[test]
+fn sutra_3_1_32_stacked_sanadi() {
+    use Sanadi::*;
+
+    // Each sanAdyanta is a dhatu, so it can take another sanAdi-pratyaya.
+    let bhu = d("BU", Bhvadi);
+    assert_has_tip(
+        &[],
+        &bhu.clone().with_sanadi(&[Ric, san]),
+        Lat,
+        &["biBAvayizati"],
+    );
+    assert_has_tip(
+        &[],
+        &bhu.clone().with_sanadi(&[san, Ric]),
+        Lat,
+        &["buBUzayati"],
+    );
+    assert_has_tip(&[], &bhu.with_sanadi(&[yaN, Ric]), Lat, &["boBUyayati"]);
+
+    // The same is true for nAmadhAtus.
+    let putriya = sanadi(p("putra"), kyac);
+    assert_has_tip(&[], &putriya.with_sanadi(&[Ric]), Lat, &["putrIyayati"]);
+    let putrakamya = sanadi(p("putra"), kAmyac);
+    assert_has_tip(
+        &[],
+        &putrakamya.with_sanadi(&[Ric]),
+        Lat,
+        &["putrakAmyayati"],
+    );
+    let lohitaya = Dhatu::nama(p("lohita"), None);
+    assert_has_tip(&[], &lohitaya.with_sanadi(&[Ric]), Lat, &["lohitAyayati"]);
+
+    // TODO: support dvitva for nAmadhAtu + san (putrIyiyizati, ...). For now, we return no
+    // results rather than forms without dvitva.
+    let putriya = sanadi(p("putra"), kyac);
+    assert_has_tip(&[], &putriya.with_sanadi(&[san]), Lat, &[]);
+}
+
 #[test]
 fn sutra_3_1_33() {
     let kf = d("qukf\\Y", Tanadi);
@@ -385,6 +434,20 @@ fn sutra_3_1_33() {
     assert_has_lut(&["sam"], &d("ga\\mx~", Bhvadi), &["saNgantA"]);
 }
 
+#[test]
+fn sutra_3_1_34() {
+    let t = Tester::with_chaandasa();
+    let pat = d("patx~", Bhvadi);
+    t.assert_has_tip(
+        &[],
+        &pat,
+        Let,
+        &[
+            "patizAti", "patizati", "patizAt", "patizat", "patAti", "patati", "patAt", "patat",
+        ],
+    );
+}
+
 #[test]
 fn sutra_3_1_35() {
     assert_has_lit(
@@ -7,6 +7,41 @@ use vidyut_prakriya::args::Linga::*;
 use vidyut_prakriya::args::Taddhita as T;
 use vidyut_prakriya::args::*;
 
+#[test]
+fn sutra_3_4_9() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_krt(&[], &d("va\\ca~", Adadi), Krt::se, &["vakze"]);
+    t.assert_has_krt(&[], &d("jIva~", Bhvadi), Krt::ase, &["jIvase"]);
+    t.assert_has_krt(
+        &["upa", "AN"],
+        &d("cara~", Bhvadi),
+        Krt::aDyE,
+        &["upAcaraDyE"],
+    );
+    t.assert_has_krt(&[], &d("pA\\", Bhvadi), Krt::SaDyE, &["pibaDyE"]);
+    t.assert_has_krt(&[], &d("pA\\", Bhvadi), Krt::tavE, &["pAtavE"]);
+    t.assert_has_krt(&[], &d("i\\R", Adadi), Krt::sen, &["eze"]);
+    t.assert_has_krt(&[], &d("jIva~", Bhvadi), Krt::asen, &["jIvase"]);
+    t.assert_has_krt(&[], &d("SriY", Bhvadi), Krt::kasen, &["Sriyase"]);
+    t.assert_has_krt(&["AN"], &d("hve\\Y", Bhvadi), Krt::kaDyE, &["AhuvaDyE"]);
+    t.assert_has_krt(&[], &d("SriY", Bhvadi), Krt::kaDyEn, &["SriyaDyE"]);
+    t.assert_has_krt(
+        &["saha"],
+        &d("madI~", Divadi).with_sanadi(&[Sanadi::Ric]),
+        Krt::SaDyEn,
+        &["sahamAdayaDyE"],
+    );
+
+    // Examples from the Rgveda.
+    t.assert_has_krt(&[], &d("zwu\\Y", Adadi), Krt::kse, &["stuze"]);
+    t.assert_has_krt(&[], &d("ga\\mx~", Bhvadi), Krt::aDyEn, &["gamaDyE"]);
+    t.assert_has_krt(&[], &d("ya\\ja~^", Bhvadi), Krt::taveN, &["yazwave"]);
+    t.assert_has_krt(&[], &d("qukf\\Y", Tanadi), Krt::taven, &["kartave"]);
+
+    // Not in ordinary usage.
+    assert_has_krdanta(&[], &d("jIva~", Bhvadi), Krt::ase, &[]);
+}
+
 #[test]
 fn sutra_3_4_12() {
     assert_has_krdanta(&["vi"], &d("Ba\\ja~^", Tudadi), Krt::Ramul, &["viBAjam"]);
@@ -252,7 +287,75 @@ fn sutra_3_4_93() {
     assert_has_mahin(&[], &kf, Lot, &["karavAmahE"]);
 }
 
-// 3.4.94 - 3.4.98 are for lew.
+#[test]
+fn sutra_3_4_94() {
+    let t = Tester::with_chaandasa();
+    let pat = d("patx~", Bhvadi);
+    t.assert_has_tas(
+        &[],
+        &pat,
+        Let,
+        &["patizAtaH", "patizataH", "patAtaH", "patataH"],
+    );
+}
+
+#[test]
+fn sutra_3_4_95() {
+    let t = Tester::with_chaandasa();
+    let mantr = d("matri~\\", Curadi);
+    t.assert_has_aataam(
+        &[],
+        &mantr,
+        Let,
+        &["mantrayEte", "mantrayizEte", "mantrEte", "mantrizEte"],
+    );
+    t.assert_has_aathaam(
+        &[],
+        &mantr,
+        Let,
+        &["mantrayETe", "mantrayizETe", "mantrETe", "mantrizETe"],
+    );
+}
+
+#[test]
+fn sutra_3_4_96() {
+    let t = Tester::with_chaandasa();
+    let pac = d("qupa\\ca~^z", Bhvadi);
+    t.assert_has_ta(
+        &[],
+        &pac,
+        Let,
+        &[
+            "pacAtE", "pacatE", "pacAte", "pacate", "pakzAtE", "pakzatE", "pakzAte", "pakzate",
+        ],
+    );
+}
+
+#[test]
+fn sutra_3_4_97() {
+    let t = Tester::with_chaandasa();
+    let pat = d("patx~", Bhvadi);
+    t.assert_has_sip(
+        &[],
+        &pat,
+        Let,
+        &[
+            "patizAsi", "patizasi", "patizAH", "patizaH", "patAsi", "patasi", "patAH", "pataH",
+        ],
+    );
+}
+
+#[test]
+fn sutra_3_4_98() {
+    let t = Tester::with_chaandasa();
+    let bhu = d("BU", Bhvadi);
+    t.assert_has_vas(
+        &[],
+        &bhu,
+        Let,
+        &["BavAva", "BavAvaH", "BavizAva", "BavizAvaH"],
+    );
+}
 
 #[test]
 fn sutra_3_4_99() {
@@ -702,6 +702,16 @@ fn sutra_6_1_69() {
     assert_has_sup_ss("katarat", Napumsaka, &["katarat"]);
 }
 
+#[test]
+fn sutra_6_1_70() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_sup_1p("vana", Napumsaka, &["vanA", "vanAni"]);
+    t.assert_has_sup_2p("viSva", Napumsaka, &["viSvA", "viSvAni"]);
+
+    // Not in ordinary usage.
+    assert_has_sup_1p("vana", Napumsaka, &["vanAni"]);
+}
+
 // saMhitAyAm ...
 
 #[test]
@@ -70,6 +70,13 @@ fn sutra_6_4_4() {
     assert_has_sup_6p("catur", Stri, &["catasfRAm"]);
 }
 
+#[test]
+fn sutra_6_4_5() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_sup_6p("tri", Stri, &["tisfRAm", "tisFRAm"]);
+    t.assert_has_sup_6p("catur", Stri, &["catasfRAm", "catasFRAm"]);
+}
+
 #[test]
 fn sutra_6_4_6() {
     assert_has_sup_6p("nf", Pum, &["nFRAm", "nfRAm"]);
@@ -104,6 +111,18 @@ fn sutra_6_4_8() {
     assert_has_sup_ss("takzan", Pum, &["takzan"]);
 }
 
+#[test]
+fn sutra_6_4_9() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_sup_2s("ukzan", Pum, &["ukzaRam", "ukzARam"]);
+    t.assert_has_sup_2s("takzan", Pum, &["takzaRam", "takzARam"]);
+    // zapUrvasya
+    t.assert_has_sup_2s("rAjan", Pum, &["rAjAnam"]);
+
+    // Not in ordinary usage.
+    assert_has_sup_2s("ukzan", Pum, &["ukzARam"]);
+}
+
 #[test]
 fn sutra_6_4_10() {
     let shreyas = taddhitanta("praSasya", T::Iyasun).with_require("Sreyas");
@@ -734,6 +753,29 @@ fn sutra_6_4_56() {
     assert_has_krdanta(&["pra"], &nic(&d("patx~", Bhvadi)), ktvA, &["prapAtya"]);
 }
 
+#[test]
+fn sutra_6_4_58() {
+    use Krt::ktvA;
+    let t = Tester::with_chaandasa();
+    // viyutvA and viyutvAya by 7.1.38 and 7.1.47.
+    t.assert_has_krt(
+        &["vi"],
+        &d("yu", Adadi),
+        ktvA,
+        &["viyUya", "viyutvA", "viyutvAya"],
+    );
+    t.assert_has_krt(
+        &["vi"],
+        &d("plu\\N", Bhvadi),
+        ktvA,
+        &["viplUya", "viplutvA", "viplutvAya"],
+    );
+
+    // Not in ordinary usage.
+    assert_has_krdanta(&["vi"], &d("yu", Adadi), ktvA, &["viyutya"]);
+    assert_has_krdanta(&["vi"], &d("plu\\N", Bhvadi), ktvA, &["viplutya"]);
+}
+
 #[ignore]
 #[test]
 fn sutra_6_4_62() {
@@ -1174,6 +1216,13 @@ fn sutra_6_4_85() {
     assert_has_sup_1p(&pratibhu, Stri, &["pratiBuvaH"]);
 }
 
+#[test]
+fn sutra_6_4_86() {
+    let t = Tester::with_chaandasa();
+    let vibhu = create_upapada_krdanta("viBU", "vi", &[], &d("BU", Bhvadi), Krt::kvip);
+    t.assert_has_sup_2s(&vibhu, Pum, &["viBuvam", "viBvam"]);
+}
+
 #[test]
 fn sutra_6_4_87() {
     let hu = d("hu\\", Juhotyadi);
@@ -1386,6 +1435,15 @@ fn sutra_6_4_101() {
     // TODO: others
 }
 
+#[test]
+fn sutra_6_4_102() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_sip(&[], &d("Sru\\", Bhvadi), Lot, &["SfRuDi", "SfRutAt"]);
+
+    // Not in ordinary usage.
+    assert_has_hi(&[], &d("Sru\\", Bhvadi), &["SfRu", "SfRutAt"]);
+}
+
 #[test]
 fn sutra_6_4_104() {
     assert_has_ta_k(&[], &d("qukf\\Y", Tanadi), Lun, &["akAri"]);
@@ -1987,6 +2045,15 @@ fn sutra_6_4_140() {
     assert_has_sup_2p("mAlA", Stri, &["mAlAH"]);
 }
 
+#[test]
+fn sutra_6_4_141() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_sup_3s("Atman", Pum, &["tmanA"]);
+
+    // Not in ordinary usage.
+    assert_has_sup_3s("Atman", Pum, &["AtmanA"]);
+}
+
 #[ignore]
 #[test]
 fn sutra_6_4_142() {
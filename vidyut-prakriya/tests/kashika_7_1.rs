@@ -129,7 +129,12 @@ fn sutra_7_1_9() {
     assert_has_sup_3p("atijarasa", Pum, &["atijarasEH"]);
 }
 
-// 7.1.10 is chAndasa
+#[test]
+fn sutra_7_1_10() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_sup_3p("deva", Pum, &["deveBiH", "devEH"]);
+    t.assert_has_sup_3p("karRa", Pum, &["karReBiH", "karREH"]);
+}
 
 #[test]
 fn sutra_7_1_11() {
@@ -288,7 +293,14 @@ fn sutra_7_1_25() {
     assert_has_sup_2s("nema", Napumsaka, &["nemam"]);
 }
 
-// 7.1.26 is chAndasa.
+#[test]
+fn sutra_7_1_26() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_sup_1s("itara", Napumsaka, &["itaram"]);
+
+    // Not in ordinary usage.
+    assert_has_sup_1s("itara", Napumsaka, &["itarat"]);
+}
 
 #[test]
 fn sutra_7_1_27() {
@@ -389,7 +401,61 @@ fn sutra_7_1_37() {
     // TODO: others
 }
 
-// 7.1.38 - 7.1.50 are chAndasa.
+#[test]
+fn sutra_7_1_38() {
+    let t = Tester::with_chaandasa();
+    let dhapi = nic(&d("quDA\\Y", Juhotyadi));
+    // pariDApayitvAya by 7.1.47.
+    t.assert_has_krt(
+        &["pari"],
+        &dhapi,
+        Krt::ktvA,
+        &["pariDApya", "pariDApayitvA", "pariDApayitvAya"],
+    );
+
+    // Not in ordinary usage.
+    assert_has_krdanta(&["pari"], &dhapi, Krt::ktvA, &["pariDApya"]);
+}
+
+// 7.1.39 - 7.1.45 are chAndasa.
+
+#[test]
+fn sutra_7_1_46() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_mas(&[], &d("asa~", Adadi), Lat, &["smasi", "smaH"]);
+    t.assert_has_mas(
+        &[],
+        &d("qupa\\ca~^z", Bhvadi),
+        Lat,
+        &["pacAmasi", "pacAmaH"],
+    );
+}
+
+#[test]
+fn sutra_7_1_47() {
+    let t = Tester::with_chaandasa();
+    let daa = d("qudA\\Y", Juhotyadi);
+    t.assert_has_krt(&[], &daa, Krt::ktvA, &["dattvA", "dattvAya"]);
+
+    // Not in ordinary usage.
+    assert_has_krdanta(&[], &daa, Krt::ktvA, &["dattvA"]);
+}
+
+// 7.1.48 - 7.1.49 are chAndasa.
+
+#[test]
+fn sutra_7_1_50() {
+    let t = Tester::with_chaandasa();
+    t.assert_has_sup_1p("brAhmaRa", Pum, &["brAhmaRAsaH", "brAhmaRAH"]);
+    t.assert_has_sup_1p("deva", Pum, &["devAsaH", "devAH"]);
+    // At only
+    t.assert_has_sup_1p("agni", Pum, &["agnayaH"]);
+    // jas only (not Si)
+    t.assert_has_sup_1p("vana", Napumsaka, &["vanA", "vanAni"]);
+
+    // Not in ordinary usage.
+    assert_has_sup_1p("deva", Pum, &["devAH"]);
+}
 
 #[test]
 fn sutra_7_1_52() {
@@ -440,6 +506,17 @@ fn sutra_7_1_55() {
     assert_has_sup_6p("catur", Pum, &["caturRAm"]);
 }
 
+#[test]
+fn sutra_7_1_56() {
+    let t = Tester::with_chaandasa();
+    let shri = create_krdanta("SrI", &[], &d("SriY", Bhvadi), Krt::kvip);
+    t.assert_has_sup_6p(&shri, Stri, &["SrIRAm"]);
+    t.assert_has_sup_6p("grAmaRI", Pum, &["grAmaRInAm"]);
+
+    // Not in ordinary usage.
+    assert_has_sup_6p(&shri, Stri, &["SrIRAm", "SriyAm"]);
+}
+
 #[test]
 fn sutra_7_1_58() {
     let kund = d("kuqi~\\", Bhvadi);
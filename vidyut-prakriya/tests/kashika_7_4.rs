@@ -463,6 +463,18 @@ fn sutra_7_4_43() {
     assert_has_krdanta(&[], &d("o~hA\\N", Juhotyadi), Krt::ktvA, &["hAtvA"]);
 }
 
+#[test]
+fn sutra_7_4_44() {
+    let t = Tester::with_chaandasa();
+    // hitvAya and hAtvAya by 7.1.47.
+    t.assert_has_krt(
+        &[],
+        &d("o~hA\\k", Juhotyadi),
+        Krt::ktvA,
+        &["hitvA", "hAtvA", "hitvAya", "hAtvAya"],
+    );
+}
+
 #[test]
 fn sutra_7_4_46() {
     let daa = d("qudA\\Y", Juhotyadi);
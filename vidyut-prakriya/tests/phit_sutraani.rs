@@ -0,0 +1,142 @@
+//! Tests for the Phit-sutras, which assign svaras to underived pratipadikas.
+//!
+//! Expected forms are the examples given for each sutra. We don't test words like *soma*, whose
+//! accent comes from an Unadi derivation rather than from the Phit-sutras.
+extern crate test_utils;
+use lazy_static::lazy_static;
+use test_utils::*;
+use vidyut_prakriya::args::Linga::*;
+use vidyut_prakriya::args::*;
+use vidyut_prakriya::Svara::{self, *};
+use vidyut_prakriya::Vyakarana;
+
+lazy_static! {
+    static ref S: Tester = Tester::with_svara_rules();
+    static ref C: Tester = Tester::new(
+        Vyakarana::builder()
+            .use_svaras(true)
+            .is_chandasi(true)
+            .build()
+    );
+}
+
+/// Asserts that the avyaya `text` receives `expected` as its svara.
+///
+/// We inspect the term directly because `derive_subantas` does not yet apply *luk* to the sup
+/// after an avyaya.
+fn assert_avyaya_svara(text: &str, expected: Svara) {
+    let v = Vyakarana::builder().use_svaras(true).build();
+    let args = Subanta::avyaya(Pratipadika::avyaya(text));
+    let prakriyas = v.derive_subantas(&args);
+    let p = prakriyas.first().expect("derived");
+    let last = p.history().last().expect("has steps");
+    assert_eq!(last.result()[0].svara(), Some(expected), "{text}");
+}
+
+/// phizo 'nta udAttaH
+#[test]
+fn phit_1_1() {
+    S.assert_has_sup_1s("agni", Pum, &["agni/H"]);
+}
+
+/// pAwalApAlaNkAmbAsAgarArTAnAm
+#[test]
+fn phit_1_2() {
+    S.assert_has_sup_1s("sAgara", Pum, &["sAgara/H"]);
+    S.assert_has_sup_1s("samudra", Pum, &["samudra/H"]);
+}
+
+/// gehArTAnAm astriyAm
+#[test]
+fn phit_1_3() {
+    S.assert_has_sup_1s("gfha", Pum, &["gfha/H"]);
+}
+
+/// SuklagOrayor AdiH
+#[test]
+fn phit_1_8() {
+    S.assert_has_sup_1s("Sukla", Pum, &["Su/klaH"]);
+    S.assert_has_sup_1s("gOra", Pum, &["gO/raH"]);
+}
+
+/// aNguzWodakabakavaSAnAM Candasy antaH
+#[test]
+fn phit_1_9() {
+    C.assert_has_sup_1s("udaka", Napumsaka, &["udaka/m"]);
+    S.assert_has_sup_1s("udaka", Napumsaka, &["u/dakam"]);
+}
+
+/// GftAdInAM ca
+#[test]
+fn phit_1_16() {
+    S.assert_has_sup_1s("Gfta", Napumsaka, &["Gfta/m"]);
+}
+
+/// hrasvAntasya strIvizayasya
+#[test]
+fn phit_2_2() {
+    S.assert_has_sup_1s("BUmi", Stri, &["BU/miH"]);
+    S.assert_has_sup_1s("ozaDi", Stri, &["o/zaDiH"]);
+}
+
+/// naBvizayasyAnisantasya
+#[test]
+fn phit_2_3() {
+    S.assert_has_sup_1s("vana", Napumsaka, &["va/nam"]);
+    S.assert_has_sup_1s("mUla", Napumsaka, &["mU/lam"]);
+    // Not napvizaya.
+    S.assert_has_sup_1s("Gfta", Napumsaka, &["Gfta/m"]);
+}
+
+/// tfRaDAnyAnAM ca dvyazAm
+#[test]
+fn phit_2_4() {
+    S.assert_has_sup_1s("yava", Pum, &["ya/vaH"]);
+    S.assert_has_sup_1s("tila", Pum, &["ti/laH"]);
+}
+
+/// SiMSumArodumbarabalIvardozwrArapurUravasAM ca
+#[test]
+fn phit_3_4() {
+    S.assert_has_sup_1s("udumbara", Pum, &["udu/mbaraH"]);
+}
+
+/// nipAtA AdyudAttAH
+#[test]
+fn phit_4_12() {
+    assert_avyaya_svara("nu", Udatta(0));
+}
+
+/// upasargAS cABivarjam
+#[test]
+fn phit_4_13() {
+    assert_avyaya_svara("upa", Udatta(0));
+    assert_avyaya_svara("A", Udatta(0));
+    assert_avyaya_svara("aBi", Udatta(1));
+}
+
+/// evAdInAm antaH
+#[test]
+fn phit_4_14() {
+    assert_avyaya_svara("eva", Udatta(1));
+}
+
+/// cAdayo 'nudAttAH
+#[test]
+fn phit_4_16() {
+    assert_avyaya_svara("ca", Anudatta);
+    assert_avyaya_svara("vA", Anudatta);
+}
+
+/// tilyaSikyakASmaryaDAnyakanyArAjanyamanuzyARAm antaH svaritaH
+#[test]
+fn phit_4_19() {
+    S.assert_has_sup_1s("rAjanya", Pum, &["rAjanya^H"]);
+    S.assert_has_sup_1s("manuzya", Pum, &["manuzya^H"]);
+}
+
+/// bilvaBakzyavIryARi Candasi
+#[test]
+fn phit_4_20() {
+    C.assert_has_sup_1s("vIrya", Napumsaka, &["vIrya^m"]);
+}
@@ -0,0 +1,110 @@
+/*!
+Tests for the `prakriya` binary, which accepts requests as flags, as JSON, or as a JSON Lines file.
+*/
+use std::process::Command;
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_prakriya"))
+        .args(args)
+        .output()
+        .expect("binary should run");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).expect("output should be UTF-8")
+}
+
+const DEVA_JSON: &str = r#"{"Subanta": {"pratipadika": {"Basic": {"text": "deva"}}, "linga": "Pum", "vibhakti": "Prathama", "vacana": "Eka"}}"#;
+
+#[test]
+fn flag_request() {
+    let out = run(&[
+        "--no-history",
+        "tinanta",
+        "--dhatu",
+        "BU",
+        "--gana",
+        "1",
+        "--lakara",
+        "lat",
+        "--purusha",
+        "prathama",
+        "--vacana",
+        "eka",
+    ]);
+    assert_eq!(out, "Bavati\n");
+}
+
+#[test]
+fn flag_request_with_script() {
+    let out = run(&[
+        "--no-history",
+        "--script",
+        "devanagari",
+        "subanta",
+        "--pratipadika",
+        "deva",
+        "--linga",
+        "pum",
+        "--vibhakti",
+        "1",
+        "--vacana",
+        "eka",
+    ]);
+    assert_eq!(out, "देवः\n");
+}
+
+#[test]
+fn json_request() {
+    let out = run(&["--no-history", "json", DEVA_JSON]);
+    assert_eq!(out, "devaH\n");
+}
+
+#[test]
+fn batch_request() {
+    let path = std::env::temp_dir().join("vidyut_prakriya_batch_request.jsonl");
+    let rama = DEVA_JSON.replace("deva", "rAma");
+    std::fs::write(&path, format!("{DEVA_JSON}\n\n{rama}\n")).expect("can write file");
+
+    let path = path.to_str().expect("path is UTF-8");
+    let out = run(&["--no-history", "batch", path]);
+    assert_eq!(out, format!("# {DEVA_JSON}\ndevaH\n# {rama}\nrAmaH\n"));
+}
+
+#[test]
+fn history_keeps_svaras() {
+    let out = run(&[
+        "--svaras",
+        "tinanta",
+        "--dhatu",
+        "BU",
+        "--gana",
+        "1",
+        "--lakara",
+        "lat",
+        "--purusha",
+        "prathama",
+        "--vacana",
+        "eka",
+    ]);
+    assert!(out.starts_with("Bava^ti\n"));
+    assert!(out.contains("6.1.159    | Ba/v + a\\ + ti\\\n"));
+
+    // Without `--svaras`, the history has no markers.
+    let out = run(&[
+        "tinanta",
+        "--dhatu",
+        "BU",
+        "--gana",
+        "1",
+        "--lakara",
+        "lat",
+        "--purusha",
+        "prathama",
+        "--vacana",
+        "eka",
+    ]);
+    assert!(out.contains("8.4.68     | Bav + a + ti\n"));
+}
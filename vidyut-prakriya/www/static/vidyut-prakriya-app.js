@@ -101,6 +101,16 @@ class Vidyut {
             krdanta.upasarga || null,
         )
     }
+
+    // Derives any request that `Vidyut::derive` accepts. For the request format, see wasm.rs.
+    derive(request) {
+        return this.wasm.derive(request);
+    }
+
+    // Returns the dhatu with the given code in the format that `derive` expects.
+    dhatu(code) {
+        return this.wasm.dhatu(code);
+    }
 }
 
 // ===================================================